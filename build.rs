@@ -0,0 +1,19 @@
+fn main() {
+    #[cfg(feature = "capi")]
+    header();
+}
+
+/// Generates the C header of the `capi` feature in `OUT_DIR`
+///
+/// tests/capi.rs checks that `include/time_humanize.h` matches it.
+#[cfg(feature = "capi")]
+fn header() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    println!("cargo:rerun-if-changed=src/capi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    cbindgen::generate(&crate_dir)
+        .expect("cannot generate the C header")
+        .write_to_file(std::path::Path::new(&out_dir).join("time_humanize.h"));
+}
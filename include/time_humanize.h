@@ -0,0 +1,55 @@
+#ifndef TIME_HUMANIZE_H
+#define TIME_HUMANIZE_H
+
+/* Generated by cbindgen from src/capi.rs, regenerate with `cbindgen --output include/time_humanize.h` */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Precise text like "in 1 hour and 30 minutes" instead of rough text like "in an hour"
+ */
+#define TIME_HUMANIZE_PRECISE 1
+
+/**
+ * Text without tense like "1 hour" instead of "in 1 hour" or "1 hour ago"
+ */
+#define TIME_HUMANIZE_PRESENT 2
+
+/**
+ * Counts as words like "in three minutes" instead of digits
+ */
+#define TIME_HUMANIZE_WORDS 4
+
+/**
+ * Writes English text for the duration `secs` + `nanos` / 10^9 seconds from now to `buf`
+ *
+ * Like `timespec`, `secs` holds the sign and `nanos` is below 10^9, so -1.5
+ * seconds is `secs` = -2 and `nanos` = 500000000. `flags` combines the
+ * `TIME_HUMANIZE_*` constants, 0 for rough text in past or future tense.
+ *
+ * Like `snprintf`, returns the length of the full text in bytes without the
+ * terminating NUL, and writes at most `len` bytes including the NUL, cutting
+ * the text at a UTF-8 character boundary. Returns -1 for `nanos` of 10^9 or
+ * more, unknown `flags`, or a NULL `buf` with `len` above 0.
+ *
+ * # Safety
+ *
+ * `buf` must be valid for writes of `len` bytes unless `len` is 0.
+ */
+int time_humanize_format(int64_t secs, uint32_t nanos, int flags, char *buf, size_t len);
+
+/**
+ * Parses text like "in 2 hours", "5 minutes ago" or "1h30m" into `secs` and `nanos`
+ *
+ * The duration is split like in `time_humanize_format`, negative for the past.
+ * Returns 0 on success, and -1 for a NULL argument or text that is no duration,
+ * which leaves `secs` and `nanos` untouched.
+ *
+ * # Safety
+ *
+ * `text` must be a NUL-terminated string, `secs` and `nanos` must be valid for writes.
+ */
+int time_humanize_parse(const char *text, int64_t *secs, uint32_t *nanos);
+
+#endif  /* TIME_HUMANIZE_H */
@@ -0,0 +1,155 @@
+use std::fmt;
+use std::time::SystemTime;
+
+use crate::calendar::civil_from_days;
+use crate::format::FormatOptions;
+use crate::humantime::{unix_seconds, HumanTime, Unit, S_DAY, S_HOUR, S_MINUTE};
+
+/// Boundaries at which ages switch to a larger unit
+///
+/// The defaults follow common pediatric usage: days for the first two weeks,
+/// weeks until 3 months, months until 2 years and years from then on.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct AgeFormat {
+    /// Age in days from which weeks are used, "2 weeks old" instead of "14 days old"
+    pub weeks_from_days: u64,
+    /// Age in months from which months are used, "3 months old" instead of "13 weeks old"
+    pub months_from_months: u64,
+    /// Age in months from which years are used, "2 years old" instead of "24 months old"
+    pub years_from_months: u64,
+}
+
+impl Default for AgeFormat {
+    /// Weeks from 14 days, months from 3 months and years from 24 months
+    fn default() -> Self {
+        Self {
+            weeks_from_days: 14,
+            months_from_months: 3,
+            years_from_months: 24,
+        }
+    }
+}
+
+/// Age of something, e.g. "3 years old" or "6 weeks old"
+///
+/// Ages below a day are given in hours, minutes or seconds. Larger ages use
+/// days, weeks, months or years depending on the boundaries of `AgeFormat`.
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use time_humanize::{Age, HumanTime};
+///
+/// assert_eq!("6 weeks old", HumanTime::from_days(45).age().to_string());
+/// assert_eq!("18 months old", HumanTime::from_days(550).age().to_string());
+///
+/// let born = SystemTime::UNIX_EPOCH;
+/// let now = born + Duration::from_secs(3 * 365 * 86400 + 86400);
+/// assert_eq!("3 years old", Age::between(born, now, 0).to_string());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Age {
+    months: u64,
+    days: u64,
+    seconds: u64,
+}
+
+impl Age {
+    /// Age on `reference` of something born on `birth`, counting calendar months
+    ///
+    /// Months and years are counted on the calendar of the zone `utc_offset`
+    /// seconds east of UTC, so the age in years changes on the birthday. Births
+    /// after `reference` give an age of zero.
+    pub fn between(birth: SystemTime, reference: SystemTime, utc_offset: i32) -> Self {
+        let birth = unix_seconds(birth) + i64::from(utc_offset);
+        let reference = unix_seconds(reference) + i64::from(utc_offset);
+        if reference <= birth {
+            return Self::default();
+        }
+
+        let day = S_DAY as i64;
+        let (birth_year, birth_month, birth_day) = civil_from_days(birth.div_euclid(day));
+        let (year, month, day_of_month) = civil_from_days(reference.div_euclid(day));
+
+        let mut months = (year - birth_year) * 12 + i64::from(month) - i64::from(birth_month);
+        if (day_of_month, reference.rem_euclid(day)) < (birth_day, birth.rem_euclid(day)) {
+            months -= 1;
+        }
+
+        let seconds = (reference - birth) as u64;
+        Self {
+            months: months as u64,
+            days: seconds / S_DAY,
+            seconds,
+        }
+    }
+
+    /// Whole years
+    pub fn years(self) -> u64 {
+        self.months / 12
+    }
+
+    /// Whole months
+    pub fn months(self) -> u64 {
+        self.months
+    }
+
+    /// Whole days
+    pub fn days(self) -> u64 {
+        self.days
+    }
+
+    /// Count and unit the age is given in with `format`
+    pub fn unit(self, format: &AgeFormat) -> (u64, Unit) {
+        match self {
+            _ if self.months >= format.years_from_months => (self.years(), Unit::Year),
+            _ if self.months >= format.months_from_months => (self.months, Unit::Month),
+            _ if self.days >= format.weeks_from_days => (self.days / 7, Unit::Week),
+            _ if self.days > 0 => (self.days, Unit::Day),
+            _ if self.seconds >= S_HOUR => (self.seconds / S_HOUR, Unit::Hour),
+            _ if self.seconds >= S_MINUTE => (self.seconds / S_MINUTE, Unit::Minute),
+            _ => (self.seconds, Unit::Second),
+        }
+    }
+
+    /// Gives English text representation with the default `AgeFormat`
+    #[must_use]
+    pub fn to_text_en(self) -> String {
+        self.to_text(&AgeFormat::default(), &FormatOptions::default())
+    }
+
+    /// Gives text representation with given `format` and `options`
+    #[must_use]
+    pub fn to_text(self, format: &AgeFormat, options: &FormatOptions) -> String {
+        let (count, unit) = self.unit(format);
+        let text = format!(
+            "{} {}",
+            options.number(count),
+            options.locale.unit_name(unit, count != 1)
+        );
+
+        options.locale.age(&text)
+    }
+}
+
+impl fmt::Display for Age {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.to_text_en())
+    }
+}
+
+impl HumanTime {
+    /// Age of something that existed for this duration, with 30-day months and 365-day years
+    ///
+    /// The sign is ignored. Use `Age::between` for ages that follow the calendar.
+    #[must_use]
+    pub fn age(self) -> Age {
+        let (years, reminder) = self.split_years();
+        let (months, _) = reminder.split_months();
+
+        Age {
+            months: years.unwrap_or(0) * 12 + months.unwrap_or(0),
+            days: self.duration().as_secs() / S_DAY,
+            seconds: self.duration().as_secs(),
+        }
+    }
+}
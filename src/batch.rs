@@ -0,0 +1,134 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::format::FormatOptions;
+use crate::humantime::{HumanTime, Unit};
+
+/// Options of `humanize_batch`
+#[derive(Clone)]
+pub struct BatchOptions {
+    unit: Option<Unit>,
+    decimals: Option<usize>,
+    pad: bool,
+    format: FormatOptions,
+}
+
+impl BatchOptions {
+    /// Pick unit and decimals from the values, without padding
+    pub fn new() -> Self {
+        Self {
+            unit: None,
+            decimals: None,
+            pad: false,
+            format: FormatOptions::default(),
+        }
+    }
+
+    /// Give all values in `unit` instead of the one fitting the largest value
+    #[must_use]
+    pub fn unit(mut self, unit: Unit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Give all values with `decimals` decimals instead of as many as they need
+    #[must_use]
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Pad all texts with leading spaces to the display width of the widest
+    #[must_use]
+    pub fn pad(mut self, pad: bool) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    /// Take unit symbols and the decimal separator from the locale of `format`
+    #[must_use]
+    pub fn format(mut self, format: FormatOptions) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Render `values` in a common unit with common decimals, e.g. for a table
+///
+/// The unit is the largest one that the largest value reaches. Values get two
+/// decimals if the largest is below 10 units and one if it is below 100 units,
+/// so "950ms" and "1s 30ms" become "0.95s" and "1.03s". Decimals that are zero
+/// in all values are dropped.
+/// Padded texts line up on the right, their width counted in columns so that
+/// symbols like "µs" take the space they are displayed in.
+///
+/// ```
+/// use std::time::Duration;
+/// use time_humanize::{humanize_batch, BatchOptions, HumanTime};
+///
+/// let millis = |ms| HumanTime::from(Duration::from_millis(ms));
+///
+/// let texts = humanize_batch(&[millis(950), millis(1030)], &BatchOptions::new());
+/// assert_eq!(vec!["0.95s", "1.03s"], texts);
+///
+/// let options = BatchOptions::new().pad(true);
+/// let texts = humanize_batch(&[millis(5), millis(42), millis(640)], &options);
+/// assert_eq!(vec!["  5ms", " 42ms", "640ms"], texts);
+/// ```
+pub fn humanize_batch(values: &[HumanTime], options: &BatchOptions) -> Vec<String> {
+    let largest = values
+        .iter()
+        .map(|value| value.duration().as_nanos())
+        .max()
+        .unwrap_or(0);
+    let unit = options.unit.unwrap_or_else(|| Unit::compact_for(largest));
+
+    let counts: Vec<f64> = values
+        .iter()
+        .map(|value| value.duration().as_nanos() as f64 / unit.as_nanos() as f64)
+        .collect();
+    let decimals = options.decimals.unwrap_or_else(|| {
+        let mut decimals = match largest / unit.as_nanos() {
+            0..=9 => 2,
+            10..=99 => 1,
+            _ => 0,
+        };
+        // Drop decimals that are zero in all values
+        while decimals > 0
+            && counts
+                .iter()
+                .all(|count| format!("{:.*}", decimals, count).ends_with('0'))
+        {
+            decimals -= 1;
+        }
+        decimals
+    });
+
+    let texts: Vec<String> = values
+        .iter()
+        .map(|value| {
+            let text = unit.compact_text(value.duration().as_nanos(), decimals, &options.format);
+            // Values rounding to zero lose their sign
+            let rounds_to_zero = !text.contains(|c: char| c.is_ascii_digit() && c != '0');
+            match value.is_negative() && !rounds_to_zero {
+                true => format!("-{}", text),
+                false => text,
+            }
+        })
+        .collect();
+
+    if !options.pad {
+        return texts;
+    }
+
+    let width = texts.iter().map(|text| text.width()).max().unwrap_or(0);
+    texts
+        .into_iter()
+        .map(|text| format!("{}{}", " ".repeat(width - text.width()), text))
+        .collect()
+}
@@ -0,0 +1,249 @@
+//! `humanize` - Display durations and timestamps in a human readable way
+//!
+//! ```text
+//! $ humanize 3600
+//! in an hour
+//! $ humanize --precise --past 95
+//! 1 minute and 35 seconds ago
+//! $ humanize --parse "in 2 days"
+//! 172800
+//! ```
+use std::env;
+use std::io;
+use std::process;
+use std::time::{Duration, SystemTime};
+
+use time_humanize::{
+    humanize_stream, Accuracy, Clock, English, FormatOptions, HumanTime, ListFormat, NearZero,
+    NumberStyle, StreamMode, StreamOptions, Style, Tense, Unit,
+};
+
+const USAGE: &str = "\
+Usage: humanize [OPTIONS] SECONDS
+       humanize [OPTIONS] --since TIMESTAMP
+       humanize [OPTIONS] --filter
+       humanize --parse TEXT
+
+Modes:
+  SECONDS                 Humanize a duration in seconds, negative for the past
+  --since TIMESTAMP       Humanize the time since a unix timestamp in seconds
+  --filter                Humanize timestamps and durations like 95s in stdin
+  --parse TEXT            Print the seconds in a phrase like \"in 2 days\"
+
+Options:
+  --rough                 Rough approximation, the default
+  --precise               Exact duration
+  --past, --future, --present
+                          Always use the given tense
+  --words                 Spell out numbers
+  --words-up-to N         Spell out numbers up to N
+  --fractional            Single unit with decimals, e.g. \"1.5 hours\"
+  --unit UNIT             Unit of fractional output, picked automatically by default
+  --decimals N            Decimals of fractional output, 1 by default
+  --clock                 Digital clock, e.g. \"01:02:03\"
+  --clock-days            Split off days in clock output, e.g. \"3d 04:05:06\"
+  --clock-no-hours        Omit zero hours in clock output
+  --clock-no-pad          Do not zero-pad the leading clock field
+  --clock-fraction N      Fractional second digits in clock output
+  --unsigned              No sign for negative clock output
+  --separator TEXT        Separator between units, \", \" by default
+  --conjunction TEXT      Separator before the last unit, \" and \" by default
+  --pair TEXT             Separator between exactly two units, \" and \" by default
+  --serial-comma          Keep the separator before the conjunction
+  --near-zero             \"just now\" and \"in a moment\" instead of \"now\"
+  --calendar              Calendar words like \"yesterday\" instead of a duration
+  --utc-offset SECONDS    UTC offset of the local zone for --calendar
+  --annotate              Keep filtered values, adding the humanized text after them
+  -h, --help              Print this help
+";
+
+enum Mode {
+    Seconds(i64),
+    Since(u64),
+    Filter,
+    Parse(String),
+}
+
+/// Everything configurable from the command line
+struct Args {
+    mode: Mode,
+    accuracy: Accuracy,
+    tense: Option<Tense>,
+    options: FormatOptions,
+    calendar: bool,
+    utc_offset: i32,
+    stream_mode: StreamMode,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut mode = None;
+        let mut accuracy = Accuracy::Rough;
+        let mut tense = None;
+        let mut numbers = NumberStyle::Digits;
+        let mut fractional = false;
+        let mut unit = None;
+        let mut decimals = 1;
+        let mut clock = None;
+        let mut list = ListFormat::default();
+        let mut custom_list = false;
+        let mut near_zero = false;
+        let mut calendar = false;
+        let mut utc_offset = 0;
+        let mut stream_mode = StreamMode::Replace;
+
+        fn value<T: std::str::FromStr>(
+            flag: &str,
+            args: &mut impl Iterator<Item = String>,
+        ) -> Result<T, String> {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} requires a value", flag))?;
+            value
+                .parse()
+                .map_err(|_| format!("invalid value \"{}\" for {}", value, flag))
+        }
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => {
+                    print!("{}", USAGE);
+                    process::exit(0);
+                }
+                "--since" => mode = Some(Mode::Since(value(&arg, &mut args)?)),
+                "--filter" => mode = Some(Mode::Filter),
+                "--parse" => mode = Some(Mode::Parse(value(&arg, &mut args)?)),
+                "--rough" => accuracy = Accuracy::Rough,
+                "--precise" => accuracy = Accuracy::Precise,
+                "--past" => tense = Some(Tense::Past),
+                "--future" => tense = Some(Tense::Future),
+                "--present" => tense = Some(Tense::Present),
+                "--words" => numbers = NumberStyle::Words,
+                "--words-up-to" => numbers = NumberStyle::WordsUpTo(value(&arg, &mut args)?),
+                "--fractional" => fractional = true,
+                "--unit" => unit = Some(value::<Unit>(&arg, &mut args)?),
+                "--decimals" => decimals = value(&arg, &mut args)?,
+                "--clock" => clock = Some(clock.unwrap_or_default()),
+                "--clock-days" => clock.get_or_insert_with(Clock::default).days = true,
+                "--clock-no-hours" => clock.get_or_insert_with(Clock::default).hours = false,
+                "--clock-no-pad" => clock.get_or_insert_with(Clock::default).pad = false,
+                "--clock-fraction" => {
+                    clock.get_or_insert_with(Clock::default).fraction = value(&arg, &mut args)?
+                }
+                "--unsigned" => clock.get_or_insert_with(Clock::default).signed = false,
+                "--separator" => {
+                    list.separator = value::<String>(&arg, &mut args)?.into();
+                    custom_list = true;
+                }
+                "--conjunction" => {
+                    list.conjunction = value::<String>(&arg, &mut args)?.into();
+                    custom_list = true;
+                }
+                "--pair" => {
+                    list.pair = value::<String>(&arg, &mut args)?.into();
+                    custom_list = true;
+                }
+                "--serial-comma" => {
+                    list.serial_comma = true;
+                    custom_list = true;
+                }
+                "--near-zero" => near_zero = true,
+                "--calendar" => calendar = true,
+                "--utc-offset" => utc_offset = value(&arg, &mut args)?,
+                "--annotate" => stream_mode = StreamMode::Annotate,
+                _ if arg.starts_with("--") => return Err(format!("unknown option {}", arg)),
+                _ => {
+                    let seconds = arg
+                        .parse()
+                        .map_err(|_| format!("invalid number of seconds \"{}\"", arg))?;
+                    mode = Some(Mode::Seconds(seconds));
+                }
+            }
+        }
+
+        let mut options = FormatOptions::new().numbers(numbers);
+        if fractional {
+            options = options.style(Style::Fractional { unit, decimals });
+        }
+        if let Some(clock) = clock {
+            options = options.style(Style::Clock(clock));
+        }
+        if custom_list {
+            options = options.list(list);
+        }
+        if near_zero {
+            options = options.near_zero(NearZero::default());
+        }
+
+        Ok(Self {
+            mode: mode.ok_or("no duration given")?,
+            accuracy,
+            tense,
+            options,
+            calendar,
+            utc_offset,
+            stream_mode,
+        })
+    }
+
+    /// Render `ht` with all options
+    fn render(&self, ht: HumanTime) -> String {
+        if self.calendar {
+            return ht.to_calendar_text(SystemTime::now(), self.utc_offset, &English);
+        }
+
+        let mut display = ht.with_options(self.options.clone());
+        if let Some(tense) = self.tense {
+            display = display.tense(tense);
+        }
+
+        match self.accuracy {
+            Accuracy::Rough => format!("{}", display),
+            Accuracy::Precise => format!("{:#}", display),
+        }
+    }
+}
+
+/// `HumanTime` from now until `timestamp`, in whole seconds
+fn since(timestamp: Duration) -> HumanTime {
+    let ht = HumanTime::between(SystemTime::UNIX_EPOCH + timestamp, SystemTime::now());
+    HumanTime::from(ht.as_secs())
+}
+
+fn main() {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("humanize: {}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match &args.mode {
+        Mode::Seconds(seconds) => println!("{}", args.render(HumanTime::from(*seconds))),
+        Mode::Since(timestamp) => {
+            println!("{}", args.render(since(Duration::from_secs(*timestamp))))
+        }
+        Mode::Parse(text) => match text.parse::<HumanTime>() {
+            Ok(ht) => {
+                let seconds = ht.duration().as_secs_f64();
+                println!("{}", if ht.is_negative() { -seconds } else { seconds });
+            }
+            Err(err) => {
+                eprintln!("humanize: {}", err);
+                process::exit(1);
+            }
+        },
+        Mode::Filter => {
+            let options = StreamOptions::new(SystemTime::now())
+                .accuracy(args.accuracy)
+                .format(args.options.clone())
+                .mode(args.stream_mode);
+            let stdin = io::stdin();
+            if let Err(err) = humanize_stream(stdin.lock(), io::stdout(), &options) {
+                eprintln!("humanize: {}", err);
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use crate::calendar::{civil_from_days, days_from_civil, Weekday};
+use crate::humantime::{unix_seconds, HumanTime, S_DAY, S_HOUR, S_MINUTE, S_WEEK};
+
+/// Error returned when a list of holidays cannot be read
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HolidayError {
+    line: usize,
+    text: String,
+}
+
+impl HolidayError {
+    /// Line of the invalid entry, counting from 1
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for HolidayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid holiday \"{}\" on line {}", self.text, self.line)
+    }
+}
+
+impl Error for HolidayError {}
+
+/// Working days, working hours and holidays that business time passes on
+///
+/// Business time only passes during the working hours of working days that are
+/// no holidays, on the wall clock of the zone `utc_offset` seconds east of UTC.
+/// Durations of business time are counted in business units: a business day
+/// lasts as long as the working hours and a business week has as many days as
+/// there are working days. Render them with a qualifier to make that clear.
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use time_humanize::{BusinessCalendar, FormatOptions};
+///
+/// let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+/// let calendar = BusinessCalendar::new();
+///
+/// // From Monday, 2024-04-29 10:00 to Thursday, 2024-05-02 10:00 UTC
+/// let age = calendar.between(at(1_714_384_800), at(1_714_644_000));
+/// let options = FormatOptions::new().qualifier("business");
+/// assert_eq!("3 business days ago", age.with_options(options).to_string());
+///
+/// // Wednesday, 2024-05-01 is a holiday
+/// let calendar = calendar.holiday(2024, 5, 1);
+/// let age = calendar.between(at(1_714_384_800), at(1_714_644_000));
+/// assert_eq!(Duration::from_secs(2 * 86400), age.duration());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    /// Whether each weekday from Monday to Sunday is a working day
+    working_days: [bool; 7],
+    /// Start of the working hours in seconds after midnight
+    opens: u64,
+    /// End of the working hours in seconds after midnight
+    closes: u64,
+    /// Holidays as days since 1970-01-01, sorted and without duplicates
+    holidays: Vec<i64>,
+    utc_offset: i32,
+}
+
+impl BusinessCalendar {
+    /// Monday to Friday from 9:00 to 17:00 UTC, without holidays
+    pub fn new() -> Self {
+        Self {
+            working_days: [true, true, true, true, true, false, false],
+            opens: 9 * S_HOUR,
+            closes: 17 * S_HOUR,
+            holidays: vec![],
+            utc_offset: 0,
+        }
+    }
+
+    /// Work on `days` only
+    #[must_use]
+    pub fn working_days(mut self, days: &[Weekday]) -> Self {
+        self.working_days = [false; 7];
+        for day in days {
+            self.working_days[*day as usize] = true;
+        }
+        self
+    }
+
+    /// Work from `opens` to `closes`, both given as hour and minute
+    ///
+    /// # Panics
+    ///
+    /// Panics if `opens` is not before `closes` or either is after 24:00.
+    #[must_use]
+    pub fn working_hours(mut self, opens: (u8, u8), closes: (u8, u8)) -> Self {
+        let seconds =
+            |(hour, minute): (u8, u8)| u64::from(hour) * S_HOUR + u64::from(minute) * S_MINUTE;
+        let (opens, closes) = (seconds(opens), seconds(closes));
+        assert!(
+            opens < closes && closes <= S_DAY,
+            "working hours must start before they end, within a day"
+        );
+
+        self.opens = opens;
+        self.closes = closes;
+        self
+    }
+
+    /// Follow the wall clock of the zone `utc_offset` seconds east of UTC
+    #[must_use]
+    pub fn utc_offset(mut self, utc_offset: i32) -> Self {
+        self.utc_offset = utc_offset;
+        self
+    }
+
+    /// Take the given date off
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date does not exist.
+    #[must_use]
+    pub fn holiday(mut self, year: i64, month: u32, day: u32) -> Self {
+        let day = date(year, month, day).expect("holiday must be a valid date");
+        self.add_holidays(vec![day]);
+        self
+    }
+
+    /// Take all dates listed in `text` off
+    ///
+    /// `text` is either an iCalendar file, whose `VEVENT` entries span the days
+    /// from `DTSTART` to before `DTEND`, or a list with one "YYYY-MM-DD" date per
+    /// line, optionally followed by a name. Empty lines and lines starting with
+    /// "#" are skipped.
+    ///
+    /// ```
+    /// use time_humanize::BusinessCalendar;
+    ///
+    /// let list = "# Company holidays\n2024-12-24 Christmas Eve\n2024-12-25\n";
+    /// let calendar = BusinessCalendar::new().holidays(list).unwrap();
+    ///
+    /// let ical = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nSUMMARY:Christmas\n\
+    ///             DTSTART;VALUE=DATE:20241224\nDTEND;VALUE=DATE:20241226\n\
+    ///             END:VEVENT\nEND:VCALENDAR\n";
+    /// assert_eq!(calendar, BusinessCalendar::new().holidays(ical).unwrap());
+    /// ```
+    pub fn holidays(mut self, text: &str) -> Result<Self, HolidayError> {
+        let is_ical = text
+            .lines()
+            .any(|line| line.trim().eq_ignore_ascii_case("BEGIN:VEVENT"));
+        let days = match is_ical {
+            true => ical_holidays(text)?,
+            false => listed_holidays(text)?,
+        };
+
+        self.add_holidays(days);
+        Ok(self)
+    }
+
+    /// Business time from `start` to `end`, zero if `end` is before `start`
+    pub fn working_time(&self, start: SystemTime, end: SystemTime) -> Duration {
+        let start = unix_seconds(start) + i64::from(self.utc_offset);
+        let end = unix_seconds(end) + i64::from(self.utc_offset);
+        let day_length = S_DAY as i64;
+
+        let mut seconds = 0;
+        for day in start.div_euclid(day_length)..=end.div_euclid(day_length) {
+            if !self.is_working_day(day) {
+                continue;
+            }
+
+            let opens = day * day_length + self.opens as i64;
+            let closes = day * day_length + self.closes as i64;
+            seconds += (closes.min(end) - opens.max(start)).max(0) as u64;
+        }
+
+        Duration::from_secs(seconds)
+    }
+
+    /// Business time between `target` and `reference`, in business units
+    ///
+    /// Like `HumanTime::between`, the result is negative if `target` lies before
+    /// `reference`. Its days and weeks are business days and business weeks.
+    pub fn between(&self, target: SystemTime, reference: SystemTime) -> HumanTime {
+        let (time, is_past) = match target >= reference {
+            true => (self.working_time(reference, target), false),
+            false => (self.working_time(target, reference), true),
+        };
+
+        let day_length = self.closes - self.opens;
+        let week_length = self.working_days.iter().filter(|day| **day).count().max(1) as u64;
+
+        let seconds = time.as_secs();
+        let (days, rest) = (seconds / day_length, seconds % day_length);
+        let (weeks, days) = (days / week_length, days % week_length);
+        let seconds = (weeks * S_WEEK + days * S_DAY + rest) as i64;
+
+        match is_past {
+            true => HumanTime::from_seconds(-seconds),
+            false => HumanTime::from_seconds(seconds),
+        }
+    }
+
+    /// Whether business time passes on the given day since 1970-01-01
+    fn is_working_day(&self, day: i64) -> bool {
+        self.working_days[Weekday::from_days(day) as usize]
+            && self.holidays.binary_search(&day).is_err()
+    }
+
+    fn add_holidays(&mut self, days: Vec<i64>) {
+        self.holidays.extend(days);
+        self.holidays.sort_unstable();
+        self.holidays.dedup();
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Days since 1970-01-01 of the given date, if it exists
+fn date(year: i64, month: u32, day: u32) -> Option<i64> {
+    let days = days_from_civil(year, month, day);
+    match civil_from_days(days) == (year, month, day) {
+        true => Some(days),
+        false => None,
+    }
+}
+
+/// Days of a list with one "YYYY-MM-DD" date per line
+fn listed_holidays(text: &str) -> Result<Vec<i64>, HolidayError> {
+    let mut days = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let first = line.split_whitespace().next().unwrap_or_default();
+        let mut parts = first.splitn(3, '-').map(str::parse::<u32>);
+        let day = match (parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(year)), Some(Ok(month)), Some(Ok(day))) => date(i64::from(year), month, day),
+            _ => None,
+        };
+
+        days.push(day.ok_or_else(|| invalid(index, line))?);
+    }
+
+    Ok(days)
+}
+
+/// Days of the `VEVENT` entries of an iCalendar file
+fn ical_holidays(text: &str) -> Result<Vec<i64>, HolidayError> {
+    let mut days = vec![];
+    let mut event: Option<(Option<i64>, Option<i64>)> = None;
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.eq_ignore_ascii_case("BEGIN:VEVENT") {
+            event = Some((None, None));
+            continue;
+        }
+        if line.eq_ignore_ascii_case("END:VEVENT") {
+            if let Some((Some(start), end)) = event.take() {
+                days.extend(start..end.unwrap_or(start + 1).max(start + 1));
+            }
+            continue;
+        }
+
+        let (start, end) = match &mut event {
+            Some(event) => event,
+            None => continue,
+        };
+        let (name, value) = line.split_once(':').unwrap_or((line, ""));
+        // Parameters like ";VALUE=DATE" follow the property name
+        match name.split(';').next() {
+            Some("DTSTART") => *start = Some(ical_date(value).ok_or_else(|| invalid(index, line))?),
+            // Only a date excludes the day it names, a date-time ends within it
+            Some("DTEND") if value.len() == 8 => {
+                *end = Some(ical_date(value).ok_or_else(|| invalid(index, line))?)
+            }
+            _ => {}
+        }
+    }
+
+    Ok(days)
+}
+
+/// Days since 1970-01-01 of an iCalendar "YYYYMMDD" date or "YYYYMMDDTHHMMSS" date-time
+fn ical_date(value: &str) -> Option<i64> {
+    let digits = value
+        .get(..8)
+        .filter(|d| d.bytes().all(|b| b.is_ascii_digit()))?;
+    date(
+        digits[..4].parse().ok()?,
+        digits[4..6].parse().ok()?,
+        digits[6..].parse().ok()?,
+    )
+}
+
+fn invalid(index: usize, line: &str) -> HolidayError {
+    HolidayError {
+        line: index + 1,
+        text: line.into(),
+    }
+}
@@ -0,0 +1,169 @@
+use std::time::SystemTime;
+
+use crate::humantime::{unix_seconds, HumanTime, S_DAY, S_HOUR, S_MINUTE};
+use crate::locale::Locale;
+
+/// Day of the week
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Weekday of the given number of days since 1970-01-01, which was a Thursday
+    pub(crate) fn from_days(days: i64) -> Self {
+        match (days + 3).rem_euclid(7) {
+            0 => Self::Monday,
+            1 => Self::Tuesday,
+            2 => Self::Wednesday,
+            3 => Self::Thursday,
+            4 => Self::Friday,
+            5 => Self::Saturday,
+            _ => Self::Sunday,
+        }
+    }
+}
+
+/// Position of a point in time relative to the calendar of a reference date
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CalendarPeriod {
+    /// Same calendar day, with the local wall clock time
+    Today {
+        hour: u8,
+        minute: u8,
+    },
+    Yesterday,
+    Tomorrow,
+    /// Within the six days before the reference day
+    LastWeekday(Weekday),
+    /// Within the six days after the reference day
+    NextWeekday(Weekday),
+    LastWeek,
+    NextWeek,
+    LastMonth,
+    NextMonth,
+    LastYear,
+    NextYear,
+    /// Too far away for a calendar word, falls back to the rough relative time
+    Relative(HumanTime),
+}
+
+impl CalendarPeriod {
+    /// Classify `target` against `reference`, both given as unix seconds, in a
+    /// zone `target_offset` and `reference_offset` seconds east of UTC at the two times
+    pub(crate) fn between(
+        target: i64,
+        reference: i64,
+        target_offset: i32,
+        reference_offset: i32,
+    ) -> Self {
+        let local_target = target + i64::from(target_offset);
+        let local_reference = reference + i64::from(reference_offset);
+
+        let target_day = local_target.div_euclid(S_DAY as i64);
+        let reference_day = local_reference.div_euclid(S_DAY as i64);
+
+        match target_day - reference_day {
+            0 => {
+                let seconds = local_target.rem_euclid(S_DAY as i64) as u64;
+                return Self::Today {
+                    hour: (seconds / S_HOUR) as u8,
+                    minute: (seconds % S_HOUR / S_MINUTE) as u8,
+                };
+            }
+            -1 => return Self::Yesterday,
+            1 => return Self::Tomorrow,
+            -6..=-2 => return Self::LastWeekday(Weekday::from_days(target_day)),
+            2..=6 => return Self::NextWeekday(Weekday::from_days(target_day)),
+            _ => {}
+        }
+
+        // Weeks start on Monday, 1970-01-01 was the fourth day of its week
+        let week = |day: i64| (day + 3).div_euclid(7);
+        match week(target_day) - week(reference_day) {
+            -1 => return Self::LastWeek,
+            1 => return Self::NextWeek,
+            _ => {}
+        }
+
+        let (target_year, target_month, _) = civil_from_days(target_day);
+        let (reference_year, reference_month, _) = civil_from_days(reference_day);
+
+        let months = (target_year * 12 + i64::from(target_month))
+            - (reference_year * 12 + i64::from(reference_month));
+        match months {
+            -1 => return Self::LastMonth,
+            1 => return Self::NextMonth,
+            _ => {}
+        }
+
+        match target_year - reference_year {
+            -1 => Self::LastYear,
+            1 => Self::NextYear,
+            _ => Self::Relative(HumanTime::from(local_target - local_reference)),
+        }
+    }
+}
+
+/// Convert days since 1970-01-01 into a (year, month, day) civil date
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// Convert a (year, month, day) civil date into days since 1970-01-01
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+/// Calendar-relative representation of `HumanTime`
+impl HumanTime {
+    /// Calendar position of this `HumanTime`, taken as an offset from `reference`,
+    /// in a zone `utc_offset` seconds east of UTC
+    pub fn calendar_period(self, reference: SystemTime, utc_offset: i32) -> CalendarPeriod {
+        let reference = unix_seconds(reference);
+        CalendarPeriod::between(
+            reference + self.as_secs(),
+            reference,
+            utc_offset,
+            utc_offset,
+        )
+    }
+
+    /// Gives calendar-relative text like "yesterday" or "next week" in the given `locale`
+    #[must_use]
+    pub fn to_calendar_text(
+        self,
+        reference: SystemTime,
+        utc_offset: i32,
+        locale: &dyn Locale,
+    ) -> String {
+        locale.calendar(self.calendar_period(reference, utc_offset))
+    }
+}
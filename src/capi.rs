@@ -0,0 +1,112 @@
+use std::convert::TryFrom;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::time::Duration;
+
+use crate::format::{FormatOptions, NumberStyle};
+use crate::humantime::{Accuracy, HumanTime, Tense};
+
+/// Precise text like "in 1 hour and 30 minutes" instead of rough text like "in an hour"
+pub const TIME_HUMANIZE_PRECISE: c_int = 1;
+/// Text without tense like "1 hour" instead of "in 1 hour" or "1 hour ago"
+pub const TIME_HUMANIZE_PRESENT: c_int = 2;
+/// Counts as words like "in three minutes" instead of digits
+pub const TIME_HUMANIZE_WORDS: c_int = 4;
+
+const FLAGS: c_int = TIME_HUMANIZE_PRECISE | TIME_HUMANIZE_PRESENT | TIME_HUMANIZE_WORDS;
+
+/// Writes English text for the duration `secs` + `nanos` / 10^9 seconds from now to `buf`
+///
+/// Like `timespec`, `secs` holds the sign and `nanos` is below 10^9, so -1.5
+/// seconds is `secs` = -2 and `nanos` = 500000000. `flags` combines the
+/// `TIME_HUMANIZE_*` constants, 0 for rough text in past or future tense.
+///
+/// Like `snprintf`, returns the length of the full text in bytes without the
+/// terminating NUL, and writes at most `len` bytes including the NUL, cutting
+/// the text at a UTF-8 character boundary. Returns -1 for `nanos` of 10^9 or
+/// more, unknown `flags`, or a NULL `buf` with `len` above 0.
+///
+/// # Safety
+///
+/// `buf` must be valid for writes of `len` bytes unless `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn time_humanize_format(
+    secs: i64,
+    nanos: u32,
+    flags: c_int,
+    buf: *mut c_char,
+    len: usize,
+) -> c_int {
+    if nanos >= 1_000_000_000 || flags & !FLAGS != 0 || (buf.is_null() && len > 0) {
+        return -1;
+    }
+
+    let ht = match secs < 0 {
+        true => -HumanTime::from(
+            Duration::from_secs(secs.unsigned_abs()) - Duration::from_nanos(nanos.into()),
+        ),
+        false => HumanTime::from(Duration::new(secs as u64, nanos)),
+    };
+    let accuracy = match flags & TIME_HUMANIZE_PRECISE {
+        0 => Accuracy::Rough,
+        _ => Accuracy::Precise,
+    };
+    let tense = match flags & TIME_HUMANIZE_PRESENT {
+        0 => ht.tense(accuracy),
+        _ => Tense::Present,
+    };
+    let mut options = FormatOptions::new();
+    if flags & TIME_HUMANIZE_WORDS != 0 {
+        options = options.numbers(NumberStyle::Words);
+    }
+
+    let text = ht.to_text(accuracy, tense, &options);
+    if len > 0 {
+        let mut end = text.len().min(len - 1);
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        ptr::copy_nonoverlapping(text.as_ptr(), buf.cast(), end);
+        *buf.add(end) = 0;
+    }
+
+    c_int::try_from(text.len()).unwrap_or(c_int::MAX)
+}
+
+/// Parses text like "in 2 hours", "5 minutes ago" or "1h30m" into `secs` and `nanos`
+///
+/// The duration is split like in `time_humanize_format`, negative for the past.
+/// Returns 0 on success, and -1 for a NULL argument or text that is no duration,
+/// which leaves `secs` and `nanos` untouched.
+///
+/// # Safety
+///
+/// `text` must be a NUL-terminated string, `secs` and `nanos` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn time_humanize_parse(
+    text: *const c_char,
+    secs: *mut i64,
+    nanos: *mut u32,
+) -> c_int {
+    if text.is_null() || secs.is_null() || nanos.is_null() {
+        return -1;
+    }
+    let ht: HumanTime = match CStr::from_ptr(text).to_str().map(str::parse) {
+        Ok(Ok(ht)) => ht,
+        _ => return -1,
+    };
+
+    let duration = ht.duration();
+    let (whole, fraction) = match i64::try_from(duration.as_secs()) {
+        Ok(whole) => (whole, duration.subsec_nanos()),
+        Err(_) => return -1,
+    };
+    match (ht.is_negative(), fraction) {
+        (false, _) => (*secs, *nanos) = (whole, fraction),
+        (true, 0) => (*secs, *nanos) = (-whole, 0),
+        (true, _) => (*secs, *nanos) = (-whole - 1, 1_000_000_000 - fraction),
+    }
+
+    0
+}
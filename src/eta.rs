@@ -0,0 +1,146 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::humantime::{Accuracy, HumanTime, Tense, S_MINUTE};
+
+/// Estimate of the time remaining in a job, from samples of its progress
+///
+/// The rate of progress is smoothed exponentially, so a single slow or fast
+/// step moves the estimate only a little. Samples carry their own timestamp,
+/// which keeps the estimate independent of the time it is rendered at.
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use time_humanize::{Eta, HumanTime};
+///
+/// let start = Instant::now();
+/// let mut eta = Eta::new();
+/// eta.sample(start, 0, 400);
+/// eta.sample(start + Duration::from_secs(10), 10, 400);
+///
+/// assert_eq!(Some(HumanTime::from_seconds(390)), eta.remaining());
+/// assert_eq!("about 6 minutes remaining", eta.to_string());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Eta {
+    smoothing: f64,
+    last: Option<(Instant, u64)>,
+    total: u64,
+    /// Smoothed units of progress per second
+    rate: Option<f64>,
+}
+
+impl Eta {
+    /// Estimator weighting each new rate by 0.3
+    pub fn new() -> Self {
+        Self {
+            smoothing: 0.3,
+            last: None,
+            total: 0,
+            rate: None,
+        }
+    }
+
+    /// Weight of each new rate between 0 and 1, where 1 disables smoothing
+    ///
+    /// # Panics
+    ///
+    /// Panics if `smoothing` is not in `(0, 1]`.
+    #[must_use]
+    pub fn smoothing(mut self, smoothing: f64) -> Self {
+        assert!(
+            smoothing > 0.0 && smoothing <= 1.0,
+            "smoothing must be in (0, 1]"
+        );
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Record that `completed` of `total` units are done at `at`
+    ///
+    /// Samples older than the last one are ignored, and progress going backwards
+    /// starts a new estimate.
+    pub fn sample(&mut self, at: Instant, completed: u64, total: u64) {
+        self.total = total;
+
+        let (last_at, last_completed) = match self.last {
+            Some(last) => last,
+            None => {
+                self.last = Some((at, completed));
+                return;
+            }
+        };
+
+        if completed < last_completed {
+            self.last = Some((at, completed));
+            self.rate = None;
+            return;
+        }
+
+        let elapsed = match at.checked_duration_since(last_at) {
+            Some(elapsed) if elapsed > Duration::from_secs(0) => elapsed,
+            _ => return,
+        };
+
+        let rate = (completed - last_completed) as f64 / elapsed.as_secs_f64();
+        self.rate = Some(match self.rate {
+            Some(smoothed) => self.smoothing * rate + (1.0 - self.smoothing) * smoothed,
+            None => rate,
+        });
+        self.last = Some((at, completed));
+    }
+
+    /// Record that `completed` of `total` units are done now
+    pub fn update(&mut self, completed: u64, total: u64) {
+        self.sample(Instant::now(), completed, total);
+    }
+
+    /// Smoothed progress in units per second, once two samples are known
+    pub fn rate(&self) -> Option<f64> {
+        self.rate
+    }
+
+    /// Time remaining after the last sample, unless no progress has been seen yet
+    pub fn remaining(&self) -> Option<HumanTime> {
+        let completed = self.last?.1;
+        if completed >= self.total {
+            return Some(HumanTime::now());
+        }
+
+        match self.rate? {
+            rate if rate > 0.0 => {
+                let secs = (self.total - completed) as f64 / rate;
+                let remaining = Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX);
+                Some(HumanTime::from(remaining))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for Eta {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Eta {
+    /// Rough text like "about 3 minutes remaining", or "done" once all units are complete
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if matches!(self.last, Some((_, completed)) if completed >= self.total) {
+            return f.write_str("done");
+        }
+
+        match self.remaining() {
+            Some(remaining) if remaining.duration() < Duration::from_secs(S_MINUTE) => {
+                f.write_str("less than a minute remaining")
+            }
+            Some(remaining) => write!(
+                f,
+                "about {} remaining",
+                remaining.to_text_en(Accuracy::Rough, Tense::Present)
+            ),
+            None => f.write_str("time remaining unknown"),
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::humantime::{pad_columns, HumanTime, Tense, Unit};
+use crate::locale::{English, Locale};
+
+/// How counts like the "2" in "2 hours ago" are written
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum NumberStyle {
+    /// Arabic numerals, e.g. "45 minutes"
+    Digits,
+    /// Spelled out by the locale, e.g. "forty-five minutes"
+    Words,
+    /// Spelled out up to and including the given number, digits above it
+    WordsUpTo(u64),
+}
+
+/// The overall shape of the rendered text
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Style {
+    /// A list of whole units, e.g. "1 hour and 30 minutes"
+    Units,
+    /// A single unit with a decimal fraction, e.g. "1.5 hours"
+    ///
+    /// Without a `unit` the largest unit that fits at least once is picked.
+    /// Accuracy does not apply to this style.
+    Fractional { unit: Option<Unit>, decimals: usize },
+    /// A digital clock, e.g. "01:02:03" or "3d 04:05:06.789"
+    ///
+    /// Accuracy and tense do not apply to this style, negative durations are
+    /// marked with a sign instead.
+    Clock(Clock),
+}
+
+/// Options of the digital clock style
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Clock {
+    /// Split whole days off the hours, e.g. "1d 02:00:00" instead of "26:00:00"
+    pub days: bool,
+    /// Show the hours field even when it is zero, "00:05:00" instead of "05:00"
+    pub hours: bool,
+    /// Zero-pad the leading field, "01:02:03" instead of "1:02:03"
+    pub pad: bool,
+    /// Number of fractional second digits, at most 9
+    pub fraction: u8,
+    /// Prefix negative durations with "-"
+    pub signed: bool,
+}
+
+impl Default for Clock {
+    /// Zero-padded `HH:MM:SS` with a sign for negative durations
+    fn default() -> Self {
+        Self {
+            days: false,
+            hours: true,
+            pad: true,
+            fraction: 0,
+            signed: true,
+        }
+    }
+}
+
+/// How the units of a list like "1 hour, 2 minutes and 3 seconds" are joined
+///
+/// Mirrors the CLDR list patterns: `separator` joins the leading items, the
+/// last item is joined with `conjunction`, and a list of exactly two items
+/// with `pair`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ListFormat {
+    /// Between all but the last two items, ", " in English
+    pub separator: Cow<'static, str>,
+    /// Before the last of three or more items, " and " in English
+    pub conjunction: Cow<'static, str>,
+    /// Between the items of a two item list, " and " in English
+    pub pair: Cow<'static, str>,
+    /// Keep the separator before the conjunction, "a, b, and c"
+    pub serial_comma: bool,
+}
+
+impl ListFormat {
+    /// Join all items with `separator` and no conjunction, e.g. "1h · 2m · 3s"
+    pub fn separated(separator: impl Into<Cow<'static, str>>) -> Self {
+        let separator = separator.into();
+        Self {
+            conjunction: separator.clone(),
+            pair: separator.clone(),
+            separator,
+            serial_comma: false,
+        }
+    }
+
+    /// Join `items` into a single list
+    pub fn join<S: AsRef<str>>(&self, items: &[S]) -> String {
+        match items {
+            [] => String::new(),
+            [one] => one.as_ref().into(),
+            [first, second] => format!("{}{}{}", first.as_ref(), self.pair, second.as_ref()),
+            [leading @ .., last] => {
+                let leading: Vec<_> = leading.iter().map(AsRef::as_ref).collect();
+                let conjunction = match self.serial_comma {
+                    true => {
+                        Cow::Owned(format!("{}{}", self.separator.trim_end(), self.conjunction))
+                    }
+                    false => Cow::Borrowed(self.conjunction.as_ref()),
+                };
+
+                format!(
+                    "{}{}{}",
+                    leading.join(&self.separator),
+                    conjunction,
+                    last.as_ref()
+                )
+            }
+        }
+    }
+}
+
+impl Default for ListFormat {
+    /// English list, "1 hour, 2 minutes and 3 seconds"
+    fn default() -> Self {
+        Self {
+            separator: ", ".into(),
+            conjunction: " and ".into(),
+            pair: " and ".into(),
+            serial_comma: false,
+        }
+    }
+}
+
+/// Phrases for durations too short to be worth mentioning, used in place of "now"
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct NearZero {
+    /// Phrase for the recent past, "just now" in English
+    pub past: Cow<'static, str>,
+    /// Phrase for the near future, "in a moment" in English
+    pub future: Cow<'static, str>,
+}
+
+impl NearZero {
+    pub fn new(past: impl Into<Cow<'static, str>>, future: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            past: past.into(),
+            future: future.into(),
+        }
+    }
+}
+
+impl Default for NearZero {
+    /// English phrases, "just now" and "in a moment"
+    fn default() -> Self {
+        Self::new("just now", "in a moment")
+    }
+}
+
+/// Options controlling how `HumanTime` is rendered into text
+#[derive(Clone)]
+pub struct FormatOptions {
+    pub(crate) locale: &'static dyn Locale,
+    pub(crate) numbers: NumberStyle,
+    pub(crate) style: Style,
+    pub(crate) list: Option<ListFormat>,
+    pub(crate) near_zero: Option<NearZero>,
+    pub(crate) qualifier: Option<Cow<'static, str>>,
+}
+
+impl FormatOptions {
+    /// English output with the default settings
+    pub fn new() -> Self {
+        Self {
+            locale: &English,
+            numbers: NumberStyle::Digits,
+            style: Style::Units,
+            list: None,
+            near_zero: None,
+            qualifier: None,
+        }
+    }
+
+    /// Use `locale` for all words
+    #[must_use]
+    pub fn locale(mut self, locale: &'static dyn Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Write counts in the given `style`
+    #[must_use]
+    pub fn numbers(mut self, style: NumberStyle) -> Self {
+        self.numbers = style;
+        self
+    }
+
+    /// Render the text in the given `style`
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Join units with `list` instead of the locale's list format
+    #[must_use]
+    pub fn list(mut self, list: ListFormat) -> Self {
+        self.list = Some(list);
+        self
+    }
+
+    /// Use `phrases` for rough durations of up to ten seconds instead of "now"
+    #[must_use]
+    pub fn near_zero(mut self, phrases: NearZero) -> Self {
+        self.near_zero = Some(phrases);
+        self
+    }
+
+    /// Put `qualifier` before all unit names, "3 business days" instead of "3 days"
+    ///
+    /// Rough text then counts single units with a number as well, "1 business
+    /// day" instead of "a day", as the article may depend on the qualifier.
+    #[must_use]
+    pub fn qualifier(mut self, qualifier: impl Into<Cow<'static, str>>) -> Self {
+        self.qualifier = Some(qualifier.into());
+        self
+    }
+
+    /// Name of `unit` from the locale, after the qualifier if there is one
+    pub(crate) fn unit_name(&self, unit: Unit, plural: bool) -> String {
+        let name = self.locale.unit_name(unit, plural);
+        match &self.qualifier {
+            Some(qualifier) => format!("{} {}", qualifier, name),
+            None => name,
+        }
+    }
+
+    /// The list format in effect, either set explicitly or from the locale
+    pub(crate) fn list_format(&self) -> Cow<'_, ListFormat> {
+        match &self.list {
+            Some(list) => Cow::Borrowed(list),
+            None => Cow::Owned(self.locale.list_format()),
+        }
+    }
+
+    /// Text for the count `n` according to the number style
+    pub(crate) fn number(&self, n: u64) -> String {
+        match self.numbers {
+            NumberStyle::Digits => n.to_string(),
+            NumberStyle::Words => self.locale.spell_number(n),
+            NumberStyle::WordsUpTo(max) if n <= max => self.locale.spell_number(n),
+            NumberStyle::WordsUpTo(_) => n.to_string(),
+        }
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `Display` adapter rendering a `HumanTime` with `FormatOptions`
+///
+/// Created by `HumanTime::with_options`. The tense follows the sign of the
+/// duration unless pinned with `Formatted::tense`.
+#[derive(Clone)]
+pub struct Formatted {
+    time: HumanTime,
+    options: FormatOptions,
+    tense: Option<Tense>,
+}
+
+impl Formatted {
+    pub(crate) fn new(time: HumanTime, options: FormatOptions) -> Self {
+        Self {
+            time,
+            options,
+            tense: None,
+        }
+    }
+
+    /// Always render in `tense`, regardless of the sign of the duration
+    #[must_use]
+    pub fn tense(mut self, tense: Tense) -> Self {
+        self.tense = Some(tense);
+        self
+    }
+}
+
+impl fmt::Display for Formatted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = self.time.display_text(f, self.tense, &self.options);
+        pad_columns(f, &text)
+    }
+}
@@ -0,0 +1,170 @@
+use std::borrow::Cow;
+use std::time::SystemTime;
+
+use crate::calendar::civil_from_days;
+use crate::format::FormatOptions;
+use crate::humantime::{unix_seconds, Accuracy, HumanTime, Tense, S_DAY, S_HOUR, S_MINUTE};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// HTML `<time>` elements, readable by people and machines alike
+impl HumanTime {
+    /// Rough English text for the point in time this far from `reference`, as HTML
+    ///
+    /// The `datetime` attribute holds the point in time in UTC for scripts, the
+    /// `title` attribute the full date for people hovering the text.
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use time_humanize::HumanTime;
+    ///
+    /// // Saturday, 2024-05-04 12:00 UTC
+    /// let reference = UNIX_EPOCH + Duration::from_secs(1_714_824_000);
+    /// assert_eq!(
+    ///     r#"<time datetime="2024-05-01T12:00:00Z" title="May 1, 2024 12:00 UTC">3 days ago</time>"#,
+    ///     HumanTime::from_days(-3).to_html(reference)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_html(self, reference: SystemTime) -> String {
+        self.to_html_with(reference, Accuracy::Rough, &FormatOptions::default())
+    }
+
+    /// Text for the point in time this far from `reference` with given `accuracy` and `options`, as HTML
+    #[must_use]
+    pub fn to_html_with(
+        self,
+        reference: SystemTime,
+        accuracy: Accuracy,
+        options: &FormatOptions,
+    ) -> String {
+        let seconds = unix_seconds(reference) + self.as_secs();
+        let day = S_DAY as i64;
+        let (year, month, date) = civil_from_days(seconds.div_euclid(day));
+        let time = seconds.rem_euclid(day) as u64;
+        let (hour, minute, second) = (time / S_HOUR, time % S_HOUR / S_MINUTE, time % S_MINUTE);
+
+        let datetime = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year, month, date, hour, minute, second
+        );
+        let title = format!(
+            "{} {}, {} {:02}:{:02} UTC",
+            MONTHS[month as usize - 1],
+            date,
+            year,
+            hour,
+            minute
+        );
+        let text = self.to_text(accuracy, self.tense(accuracy), options);
+
+        element(&datetime, Some(&title), &text)
+    }
+
+    /// Text of this duration with given `accuracy` and `options`, as HTML
+    ///
+    /// The `datetime` attribute holds the duration as ISO 8601 duration, in the
+    /// form HTML accepts, which counts days at most.
+    ///
+    /// ```
+    /// use time_humanize::{Accuracy, FormatOptions, HumanTime};
+    ///
+    /// let options = FormatOptions::new();
+    /// let html = HumanTime::from_seconds(5400).to_duration_html(Accuracy::Precise, &options);
+    /// assert_eq!(r#"<time datetime="PT1H30M">1 hour and 30 minutes</time>"#, html);
+    /// ```
+    #[must_use]
+    pub fn to_duration_html(self, accuracy: Accuracy, options: &FormatOptions) -> String {
+        let text = self.to_text(accuracy, Tense::Present, options);
+        element(&self.to_iso8601(), None, &text)
+    }
+
+    /// The length of this duration as ISO 8601 duration like "P1DT2H30M", regardless of its sign
+    ///
+    /// Only days, hours, minutes and seconds are used, as the other units have
+    /// no fixed length. Fractions of seconds are kept.
+    #[must_use]
+    pub fn to_iso8601(self) -> String {
+        let duration = self.duration();
+        let seconds = duration.as_secs();
+        let (days, hours) = (seconds / S_DAY, seconds % S_DAY / S_HOUR);
+        let (minutes, seconds) = (seconds % S_HOUR / S_MINUTE, seconds % S_MINUTE);
+
+        let mut iso = String::from("P");
+        if days > 0 {
+            iso += &format!("{}D", days);
+        }
+
+        let mut time = String::new();
+        if hours > 0 {
+            time += &format!("{}H", hours);
+        }
+        if minutes > 0 {
+            time += &format!("{}M", minutes);
+        }
+        match duration.subsec_nanos() {
+            0 if seconds > 0 || (days == 0 && time.is_empty()) => time += &format!("{}S", seconds),
+            0 => {}
+            nanos => {
+                let fraction = format!("{:09}", nanos);
+                time += &format!("{}.{}S", seconds, fraction.trim_end_matches('0'));
+            }
+        }
+
+        if !time.is_empty() {
+            iso += "T";
+            iso += &time;
+        }
+        iso
+    }
+}
+
+/// A `<time>` element with the given attributes and text
+fn element(datetime: &str, title: Option<&str>, text: &str) -> String {
+    match title {
+        Some(title) => format!(
+            r#"<time datetime="{}" title="{}">{}</time>"#,
+            escape(datetime),
+            escape(title),
+            escape(text)
+        ),
+        None => format!(
+            r#"<time datetime="{}">{}</time>"#,
+            escape(datetime),
+            escape(text)
+        ),
+    }
+}
+
+/// `text` with the characters that are special in HTML text and attributes escaped
+fn escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\''].as_ref()) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut escaped = String::with_capacity(text.len() + 8);
+    for c in text.chars() {
+        match c {
+            '&' => escaped += "&amp;",
+            '<' => escaped += "&lt;",
+            '>' => escaped += "&gt;",
+            '"' => escaped += "&quot;",
+            '\'' => escaped += "&#39;",
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
@@ -1,11 +1,15 @@
 use std::borrow::Cow;
 use std::cmp::max;
-use std::fmt;
-use std::ops::{Add, Sub};
+use std::fmt::{self, Write as _};
+use std::ops::{Add, Neg, Sub};
 use std::time::{Duration, SystemTime};
 
 use std::convert::TryInto;
 
+use unicode_width::UnicodeWidthStr;
+
+use crate::format::{Clock, FormatOptions, Formatted, Style};
+
 #[cfg(feature = "time")]
 use time::OffsetDateTime;
 
@@ -40,15 +44,115 @@ impl Accuracy {
     }
 }
 
+/// A unit of time
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Unit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Unit {
+    /// All units, from the largest to the smallest
+    pub(crate) const DESCENDING: [Unit; 10] = [
+        Unit::Year,
+        Unit::Month,
+        Unit::Week,
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+        Unit::Millisecond,
+        Unit::Microsecond,
+        Unit::Nanosecond,
+    ];
+
+    /// Units written as symbols in compact text like "2.3s", from the largest to the smallest
+    pub(crate) const COMPACT: [Unit; 7] = [
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+        Unit::Millisecond,
+        Unit::Microsecond,
+        Unit::Nanosecond,
+    ];
+
+    /// Length of one unit in nanoseconds
+    pub(crate) fn as_nanos(self) -> u128 {
+        const NANOS: u128 = 1_000_000_000;
+        match self {
+            Self::Nanosecond => 1,
+            Self::Microsecond => 1_000,
+            Self::Millisecond => 1_000_000,
+            Self::Second => NANOS,
+            Self::Minute => u128::from(S_MINUTE) * NANOS,
+            Self::Hour => u128::from(S_HOUR) * NANOS,
+            Self::Day => u128::from(S_DAY) * NANOS,
+            Self::Week => u128::from(S_WEEK) * NANOS,
+            Self::Month => u128::from(S_MONTH) * NANOS,
+            Self::Year => u128::from(S_YEAR) * NANOS,
+        }
+    }
+
+    /// The largest compact unit that `nanos` reach, seconds below a nanosecond
+    pub(crate) fn compact_for(nanos: u128) -> Self {
+        Self::COMPACT
+            .iter()
+            .copied()
+            .find(|unit| nanos >= unit.as_nanos())
+            .unwrap_or(Unit::Second)
+    }
+
+    /// `nanos` in this unit with `decimals` and the unit symbol, e.g. "2.3s"
+    pub(crate) fn compact_text(
+        self,
+        nanos: u128,
+        decimals: usize,
+        options: &FormatOptions,
+    ) -> String {
+        let value = format!("{:.*}", decimals, nanos as f64 / self.as_nanos() as f64);
+        let value = value.replace('.', options.locale.decimal_separator());
+        format!("{}{}", value, options.locale.unit_symbol(self))
+    }
+}
+
 // Number of seconds in various time periods
-const S_MINUTE: u64 = 60;
-const S_HOUR: u64 = S_MINUTE * 60;
-const S_DAY: u64 = S_HOUR * 24;
-const S_WEEK: u64 = S_DAY * 7;
-const S_MONTH: u64 = S_DAY * 30;
-const S_YEAR: u64 = S_DAY * 365;
-
-#[derive(Clone, Copy, Debug)]
+pub(crate) const S_MINUTE: u64 = 60;
+pub(crate) const S_HOUR: u64 = S_MINUTE * 60;
+pub(crate) const S_DAY: u64 = S_HOUR * 24;
+pub(crate) const S_WEEK: u64 = S_DAY * 7;
+pub(crate) const S_MONTH: u64 = S_DAY * 30;
+pub(crate) const S_YEAR: u64 = S_DAY * 365;
+
+/// Upper bounds in seconds of the bands of the rough ladder
+const ROUGH_THRESHOLDS: [u64; 13] = [
+    10,
+    45,
+    90,
+    45 * S_MINUTE,
+    90 * S_MINUTE,
+    22 * S_HOUR,
+    36 * S_HOUR,
+    6 * S_DAY + 12 * S_HOUR,
+    10 * S_DAY + 12 * S_HOUR,
+    29 * S_DAY,
+    45 * S_DAY,
+    345 * S_DAY,
+    547 * S_DAY,
+];
+
+/// Units counted by the rough ladder, in seconds
+const ROUGH_UNITS: [u64; 7] = [1, S_MINUTE, S_HOUR, S_DAY, S_WEEK, S_MONTH, S_YEAR];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 enum TimePeriod {
     Now,
     Nanos(u64),
@@ -65,57 +169,47 @@ enum TimePeriod {
 }
 
 impl TimePeriod {
-    fn to_text_precise(self) -> Cow<'static, str> {
-        match self {
-            Self::Now => "now".into(),
-            Self::Nanos(n) => format!("{} ns", n).into(),
-            Self::Micros(n) => format!("{} µs", n).into(),
-            Self::Millis(n) => format!("{} ms", n).into(),
-            Self::Seconds(1) => "1 second".into(),
-            Self::Seconds(n) => format!("{} seconds", n).into(),
-            Self::Minutes(1) => "1 minute".into(),
-            Self::Minutes(n) => format!("{} minutes", n).into(),
-            Self::Hours(1) => "1 hour".into(),
-            Self::Hours(n) => format!("{} hours", n).into(),
-            Self::Days(1) => "1 day".into(),
-            Self::Days(n) => format!("{} days", n).into(),
-            Self::Weeks(1) => "1 week".into(),
-            Self::Weeks(n) => format!("{} weeks", n).into(),
-            Self::Months(1) => "1 month".into(),
-            Self::Months(n) => format!("{} months", n).into(),
-            Self::Years(1) => "1 year".into(),
-            Self::Years(n) => format!("{} years", n).into(),
-            Self::Eternity => "eternity".into(),
-        }
-    }
-
-    fn to_text_rough(self) -> Cow<'static, str> {
+    /// Unit and count of this period, `None` for `Now` and `Eternity`
+    fn unit(self) -> Option<(Unit, u64)> {
         match self {
-            Self::Now => "now".into(),
-            Self::Nanos(n) => format!("{} ns", n).into(),
-            Self::Micros(n) => format!("{} µs", n).into(),
-            Self::Millis(n) => format!("{} ms", n).into(),
-            Self::Seconds(n) => format!("{} seconds", n).into(),
-            Self::Minutes(1) => "a minute".into(),
-            Self::Minutes(n) => format!("{} minutes", n).into(),
-            Self::Hours(1) => "an hour".into(),
-            Self::Hours(n) => format!("{} hours", n).into(),
-            Self::Days(1) => "a day".into(),
-            Self::Days(n) => format!("{} days", n).into(),
-            Self::Weeks(1) => "a week".into(),
-            Self::Weeks(n) => format!("{} weeks", n).into(),
-            Self::Months(1) => "a month".into(),
-            Self::Months(n) => format!("{} months", n).into(),
-            Self::Years(1) => "a year".into(),
-            Self::Years(n) => format!("{} years", n).into(),
-            Self::Eternity => "eternity".into(),
-        }
-    }
-
-    fn to_text(self, accuracy: Accuracy) -> Cow<'static, str> {
+            Self::Now | Self::Eternity => None,
+            Self::Nanos(n) => Some((Unit::Nanosecond, n)),
+            Self::Micros(n) => Some((Unit::Microsecond, n)),
+            Self::Millis(n) => Some((Unit::Millisecond, n)),
+            Self::Seconds(n) => Some((Unit::Second, n)),
+            Self::Minutes(n) => Some((Unit::Minute, n)),
+            Self::Hours(n) => Some((Unit::Hour, n)),
+            Self::Days(n) => Some((Unit::Day, n)),
+            Self::Weeks(n) => Some((Unit::Week, n)),
+            Self::Months(n) => Some((Unit::Month, n)),
+            Self::Years(n) => Some((Unit::Year, n)),
+        }
+    }
+
+    fn to_text_precise(self, options: &FormatOptions) -> Cow<'static, str> {
+        match self.unit() {
+            Some((unit, n)) => {
+                format!("{} {}", options.number(n), options.unit_name(unit, n != 1)).into()
+            }
+            None if matches!(self, Self::Now) => options.locale.now().into(),
+            None => "eternity".into(),
+        }
+    }
+
+    fn to_text_rough(self, options: &FormatOptions) -> Cow<'static, str> {
+        match self.unit() {
+            // The article may depend on the qualifier, so qualified units take a count
+            Some((unit, 1)) if options.qualifier.is_none() && unit >= Unit::Minute => {
+                options.locale.single_unit(unit).into()
+            }
+            _ => self.to_text_precise(options),
+        }
+    }
+
+    fn to_text(self, accuracy: Accuracy, options: &FormatOptions) -> Cow<'static, str> {
         match accuracy {
-            Accuracy::Rough => self.to_text_rough(),
-            Accuracy::Precise => self.to_text_precise(),
+            Accuracy::Rough => self.to_text_rough(options),
+            Accuracy::Precise => self.to_text_precise(options),
         }
     }
 }
@@ -128,8 +222,6 @@ pub struct HumanTime {
 }
 
 impl HumanTime {
-    const DAYS_IN_MONTH: u64 = 30;
-
     /// Create `HumanTime` object that corresponds to the current point in time.
     ///. Similar to `chrono::Utc::now()`
     pub fn now() -> Self {
@@ -142,27 +234,193 @@ impl HumanTime {
     /// Gives English text representation of the `HumanTime` with given `accuracy` and 'tense`
     #[must_use]
     pub fn to_text_en(self, accuracy: Accuracy, tense: Tense) -> String {
-        let mut periods = match accuracy {
+        self.to_text(accuracy, tense, &FormatOptions::default())
+    }
+
+    /// Gives text representation of the `HumanTime` with given `accuracy`, `tense` and `options`
+    #[must_use]
+    pub fn to_text(self, accuracy: Accuracy, tense: Tense, options: &FormatOptions) -> String {
+        let text = match options.style {
+            Style::Units if self.is_near_zero(accuracy) => {
+                return self.near_zero_text(tense, options)
+            }
+            Style::Units => self.units_text(accuracy, options),
+            Style::Fractional { unit, decimals } => self.fractional_text(unit, decimals, options),
+            Style::Clock(clock) => return self.clock_text(clock),
+        };
+
+        options.locale.with_tense(&text, tense)
+    }
+
+    /// Whether this is too short to be worth mentioning with the given `accuracy`
+    pub(crate) fn is_near_zero(self, accuracy: Accuracy) -> bool {
+        accuracy.is_rough() && self.duration.as_secs() <= 10
+    }
+
+    /// Text for a near-zero duration, which takes no "ago" or "in"
+    ///
+    /// Without near-zero phrases in `options` this is always "now", otherwise the
+    /// phrase follows `tense`, or the sign of the duration for `Tense::Present`.
+    fn near_zero_text(self, tense: Tense, options: &FormatOptions) -> String {
+        let phrases = match &options.near_zero {
+            Some(phrases) => phrases,
+            None => return options.locale.now(),
+        };
+
+        let is_future = match tense {
+            Tense::Past => false,
+            Tense::Future => true,
+            Tense::Present => self.is_positive && !self.is_zero(),
+        };
+
+        match is_future {
+            true => phrases.future.clone().into_owned(),
+            false => phrases.past.clone().into_owned(),
+        }
+    }
+
+    /// Text as a list of whole units, e.g. "1 hour and 30 minutes"
+    fn units_text(self, accuracy: Accuracy, options: &FormatOptions) -> String {
+        let periods = match accuracy {
             Accuracy::Rough => self.rough_period(),
             Accuracy::Precise => self.precise_period(),
         };
 
-        let first = periods.remove(0).to_text(accuracy);
-        let last = periods.pop().map(|last| last.to_text(accuracy));
+        let texts: Vec<_> = periods
+            .into_iter()
+            .map(|p| p.to_text(accuracy, options))
+            .collect();
+
+        options.list_format().join(&texts)
+    }
+
+    /// Text for `Display` with `options`, following the precision and width of `f`
+    ///
+    /// Without either this is rough text, or precise text in alternate mode. The
+    /// units style otherwise renders `fitting_text`. Without a `tense` the text
+    /// takes the tense of the duration.
+    pub(crate) fn display_text(
+        self,
+        f: &fmt::Formatter<'_>,
+        tense: Option<Tense>,
+        options: &FormatOptions,
+    ) -> String {
+        let fitting = f.precision().is_some() || f.width().is_some();
+        if fitting && matches!(options.style, Style::Units) {
+            let tense = tense.unwrap_or_else(|| self.tense(Accuracy::Precise));
+            return self.fitting_text(f.precision(), f.width(), tense, options);
+        }
 
-        let mut text = periods.into_iter().fold(first, |acc, p| {
-            format!("{}, {}", acc, p.to_text(accuracy)).into()
+        let accuracy = if f.alternate() {
+            Accuracy::Precise
+        } else {
+            Accuracy::Rough
+        };
+        let tense = tense.unwrap_or_else(|| self.tense(accuracy));
+        self.to_text(accuracy, tense, options)
+    }
+
+    /// Precise text of at most `units` units that fits in `width` columns
+    ///
+    /// Trailing units of the precise breakdown are dropped until the text fits,
+    /// "1 hour and 30 minutes" becomes "1 hour". The largest unit is always kept,
+    /// even if the text is wider than `width`.
+    fn fitting_text(
+        self,
+        units: Option<usize>,
+        width: Option<usize>,
+        tense: Tense,
+        options: &FormatOptions,
+    ) -> String {
+        let texts: Vec<_> = self
+            .precise_period()
+            .into_iter()
+            .map(|p| p.to_text(Accuracy::Precise, options))
+            .collect();
+        let text = |count: usize| {
+            let list = options.list_format().join(&texts[..count]);
+            options.locale.with_tense(&list, tense)
+        };
+
+        let most = units.unwrap_or(texts.len()).clamp(1, texts.len());
+        (1..=most)
+            .rev()
+            .map(text)
+            .find(|text| text.width() <= width.unwrap_or(usize::MAX))
+            .unwrap_or_else(|| text(1))
+    }
+
+    /// Text as a single decimal value, e.g. "1.5 hours"
+    ///
+    /// Without an explicit `unit` the largest unit that fits at least once is used.
+    fn fractional_text(
+        self,
+        unit: Option<Unit>,
+        decimals: usize,
+        options: &FormatOptions,
+    ) -> String {
+        let nanos = self.duration.as_nanos();
+        let unit = unit.unwrap_or_else(|| {
+            Unit::DESCENDING
+                .iter()
+                .copied()
+                .find(|unit| nanos >= unit.as_nanos())
+                .unwrap_or(Unit::Second)
         });
 
-        if let Some(last) = last {
-            text = format!("{} and {}", text, last).into();
+        let value = nanos as f64 / unit.as_nanos() as f64;
+        let text = format!("{:.*}", decimals, value);
+        let plural = text.parse::<f64>() != Ok(1.0);
+        let text = text.replace('.', options.locale.decimal_separator());
+
+        format!("{} {}", text, options.unit_name(unit, plural))
+    }
+
+    /// Text as a digital clock, e.g. "01:02:03" or "3d 04:05:06.789"
+    fn clock_text(self, clock: Clock) -> String {
+        let (days, reminder) = match clock.days {
+            true => self.split_days(),
+            false => (None, self),
+        };
+        let (hours, reminder) = reminder.split_hours();
+        let (minutes, reminder) = reminder.split_minutes();
+        let (seconds, reminder) = reminder.split_seconds();
+
+        let mut text = String::new();
+        if clock.signed && !self.is_positive && !self.is_zero() {
+            text.push('-');
+        }
+
+        let width = if clock.pad { 2 } else { 1 };
+        match (days, hours) {
+            (Some(days), hours) => text += &format!("{}d {:02}:", days, hours.unwrap_or(0)),
+            (None, Some(hours)) => text += &format!("{:0w$}:", hours, w = width),
+            (None, None) if clock.hours => text += &format!("{:0w$}:", 0, w = width),
+            (None, None) => {}
         }
 
-        match tense {
-            Tense::Past => format!("{} ago", text),
-            Tense::Future => format!("in {}", text),
-            Tense::Present => text.into_owned(),
+        let width = if text.ends_with(':') { 2 } else { width };
+        text += &format!(
+            "{:0w$}:{:02}",
+            minutes.unwrap_or(0),
+            seconds.unwrap_or(0),
+            w = width
+        );
+
+        let digits = u32::from(clock.fraction.min(9));
+        if digits > 0 {
+            let fraction = reminder.duration.subsec_nanos() / 10u32.pow(9 - digits);
+            text += &format!(".{:0w$}", fraction, w = digits as usize);
         }
+
+        text
+    }
+
+    /// Adapter displaying this `HumanTime` with the given `options`
+    ///
+    /// Like `HumanTime` itself, the adapter follows the alternate flag, precision and width.
+    pub fn with_options(self, options: FormatOptions) -> Formatted {
+        Formatted::new(self, options)
     }
 
     /// Return `HumanTime` for given seconds from epoch start
@@ -197,7 +455,7 @@ impl HumanTime {
         duration.as_secs() as i64
     }
 
-    fn tense(self, accuracy: Accuracy) -> Tense {
+    pub(crate) fn tense(self, accuracy: Accuracy) -> Tense {
         match self.duration.as_secs() {
             0..=10 if accuracy.is_rough() => Tense::Present,
             _ if !self.is_positive => Tense::Past,
@@ -207,7 +465,12 @@ impl HumanTime {
     }
 
     fn rough_period(self) -> Vec<TimePeriod> {
-        let period = match self.duration.as_secs() {
+        vec![Self::rough_period_of(self.duration.as_secs())]
+    }
+
+    /// The single rough period for a duration of `secs` whole seconds
+    fn rough_period_of(secs: u64) -> TimePeriod {
+        match secs {
             n if n > 547 * S_DAY => TimePeriod::Years(max(n / S_YEAR, 2)),
             n if n > 345 * S_DAY => TimePeriod::Years(1),
             n if n > 45 * S_DAY => TimePeriod::Months(max(n / S_MONTH, 2)),
@@ -223,9 +486,118 @@ impl HumanTime {
             n if n > 10 => TimePeriod::Seconds(n),
             0..=10 => TimePeriod::Now,
             _ => TimePeriod::Eternity,
+        }
+    }
+
+    /// Unit and count of the text with `accuracy`, if it is a single unit like "3 hours"
+    pub(crate) fn single_unit(self, accuracy: Accuracy) -> Option<(Unit, u64)> {
+        let periods = match accuracy {
+            Accuracy::Rough => self.rough_period(),
+            Accuracy::Precise => self.precise_period(),
         };
 
-        vec![period]
+        match periods.as_slice() {
+            [period] => period.unit(),
+            _ => None,
+        }
+    }
+
+    /// Unit and count of the rough ladder for this duration, in seconds below its first band
+    pub(crate) fn rough_unit(self) -> (Unit, u64) {
+        let secs = self.duration.as_secs();
+        Self::rough_period_of(secs)
+            .unit()
+            .unwrap_or((Unit::Second, secs))
+    }
+
+    /// Time until the text of this `HumanTime` with `accuracy` changes
+    ///
+    /// The point in time this `HumanTime` refers to moves into the past as time
+    /// passes, so past durations grow and future ones shrink towards "now".
+    /// Rough text follows the thresholds of the rough ladder. Precise text shows
+    /// every nanosecond, so it changes with the next nanosecond that passes.
+    #[must_use]
+    pub fn next_change(self, accuracy: Accuracy) -> Duration {
+        if accuracy.is_precise() {
+            return Duration::from_nanos(1);
+        }
+
+        let secs = self.duration.as_secs();
+        if !self.is_positive || self.is_zero() {
+            return Duration::from_secs(Self::rough_change_above(secs)) - self.duration;
+        }
+
+        match Self::rough_change_below(secs) {
+            // The text changes as soon as the duration drops below `m + 1` seconds
+            Some(m) => self.duration - Duration::from_secs(m + 1) + Duration::from_nanos(1),
+            // "now" on the future side turns into "now" on the past side
+            None => self.duration + Duration::from_secs(Self::rough_change_above(0)),
+        }
+    }
+
+    /// The smallest number of seconds above `secs` with a different rough period
+    fn rough_change_above(secs: u64) -> u64 {
+        let period = Self::rough_period_of(secs);
+        let mut candidates: Vec<u64> = ROUGH_THRESHOLDS
+            .iter()
+            .filter(|&&threshold| threshold >= secs)
+            .map(|threshold| threshold + 1)
+            .chain(
+                // Counts are clamped to at least 2, so the next multiple may not change the text
+                ROUGH_UNITS.iter().flat_map(|unit| {
+                    (1..=2).map(move |k| (secs / unit).saturating_add(k).saturating_mul(*unit))
+                }),
+            )
+            .collect();
+        candidates.sort_unstable();
+
+        candidates
+            .into_iter()
+            .find(|&candidate| Self::rough_period_of(candidate) != period)
+            .unwrap_or(u64::MAX)
+    }
+
+    /// The largest number of seconds below `secs` with a different rough period
+    fn rough_change_below(secs: u64) -> Option<u64> {
+        let period = Self::rough_period_of(secs);
+        let mut candidates: Vec<u64> = ROUGH_THRESHOLDS
+            .iter()
+            .copied()
+            .filter(|&threshold| threshold < secs)
+            .chain(
+                ROUGH_UNITS
+                    .iter()
+                    .filter(|&&unit| secs >= unit)
+                    .map(|unit| secs / unit * unit - 1),
+            )
+            .collect();
+        candidates.sort_unstable();
+
+        candidates
+            .into_iter()
+            .rev()
+            .find(|&candidate| Self::rough_period_of(candidate) != period)
+    }
+
+    /// Time until the text of this `HumanTime` cut to whole seconds with `accuracy` changes
+    ///
+    /// Like `next_change`, for labels that show whole seconds only, as
+    /// `HumanTime::from(SystemTime)` does. Rough text reads whole seconds anyway,
+    /// precise text changes as soon as the number of whole seconds does.
+    #[must_use]
+    pub fn next_change_in_seconds(self, accuracy: Accuracy) -> Duration {
+        if accuracy.is_rough() {
+            return self.next_change(accuracy);
+        }
+
+        let fraction = Duration::from_nanos(u64::from(self.duration.subsec_nanos()));
+        match (self.is_positive && !self.is_zero(), self.duration.as_secs()) {
+            // Cut to whole seconds, the future below a second reads like zero
+            // until a whole second has passed since
+            (true, 0) => self.duration + Duration::from_secs(1),
+            (true, _) => fraction + Duration::from_nanos(1),
+            (false, _) => Duration::from_secs(1) - fraction,
+        }
     }
 
     fn precise_period(self) -> Vec<TimePeriod> {
@@ -291,16 +663,16 @@ impl HumanTime {
     }
 
     /// Split this `HumanTime` into number of whole years and the reminder
-    fn split_years(self) -> (Option<u64>, Self) {
+    pub(crate) fn split_years(self) -> (Option<u64>, Self) {
         let years = self.duration.as_secs() / S_YEAR;
         let reminder = self.duration - Duration::new(years * S_YEAR, 0);
         Self::normalize_split(years, reminder)
     }
 
     /// Split this `HumanTime` into number of whole months and the reminder
-    fn split_months(self) -> (Option<u64>, Self) {
+    pub(crate) fn split_months(self) -> (Option<u64>, Self) {
         let months = self.duration.as_secs() / S_MONTH;
-        let reminder = self.duration - Duration::new(months * Self::DAYS_IN_MONTH, 0);
+        let reminder = self.duration - Duration::new(months * S_MONTH, 0);
         Self::normalize_split(months, reminder)
     }
 
@@ -381,24 +753,40 @@ impl HumanTime {
         self.duration.is_zero()
     }
 
-    /// Return a string represenation for a given `Accuracy`
-    fn locale_en(&self, accuracy: Accuracy) -> String {
-        let tense = self.tense(accuracy);
-        self.to_text_en(accuracy, tense)
-    }
-
     /// Return duration as seconds, can be negative
-    fn as_secs(&self) -> i64 {
+    pub fn as_secs(&self) -> i64 {
         if self.is_positive {
             self.duration.as_secs() as i64
         } else {
             -(self.duration.as_secs() as i64)
         }
     }
+
+    /// Return the length of the duration, regardless of its sign
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Check if `HumanTime` lies in the past
+    pub fn is_negative(&self) -> bool {
+        !self.is_positive
+    }
 }
 
 /// Instantiate `HumanTime` from different time metrics
 impl HumanTime {
+    /// Instantiate `HumanTime` for the time from `reference` to `target`, positive
+    /// if `target` lies in the future
+    pub fn between(target: SystemTime, reference: SystemTime) -> HumanTime {
+        match target.duration_since(reference) {
+            Ok(duration) => HumanTime::from(duration),
+            Err(err) => Self {
+                duration: err.duration(),
+                is_positive: false,
+            },
+        }
+    }
+
     /// Instantiate `HumanTime` for given seconds
     pub fn from_seconds(seconds: i64) -> HumanTime {
         HumanTime::from(seconds)
@@ -436,14 +824,15 @@ impl HumanTime {
 }
 
 impl fmt::Display for HumanTime {
+    /// Rough text, or precise text in alternate mode
+    ///
+    /// A precision limits the text to that many units, `{:.2}` renders "1 day and
+    /// 2 hours" instead of "1 day, 2 hours and 3 minutes". A width picks the most
+    /// detailed text that fits in that many columns and pads it. Either makes the
+    /// text precise.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let accuracy = if f.alternate() {
-            Accuracy::Precise
-        } else {
-            Accuracy::Rough
-        };
-
-        f.pad(&self.locale_en(accuracy))
+        let text = self.display_text(f, None, &FormatOptions::default());
+        pad_columns(f, &text)
     }
 }
 
@@ -473,6 +862,17 @@ impl Sub for HumanTime {
     }
 }
 
+impl Neg for HumanTime {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            duration: self.duration,
+            is_positive: !self.is_positive || self.is_zero(),
+        }
+    }
+}
+
 impl From<SystemTime> for HumanTime {
     fn from(st: SystemTime) -> Self {
         match st.duration_since(SystemTime::now()) {
@@ -503,6 +903,34 @@ impl Into<OffsetDateTime> for HumanTime {
     }
 }
 
+/// Write `text` padded to the width of `f`, counting display columns rather than chars
+pub(crate) fn pad_columns(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(text.width());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+
+    for _ in 0..before {
+        f.write_char(f.fill())?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        f.write_char(f.fill())?;
+    }
+
+    Ok(())
+}
+
+/// Seconds between the unix epoch and `time`, negative for times before the epoch
+pub(crate) fn unix_seconds(time: SystemTime) -> i64 {
+    match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(err) => -(err.duration().as_secs() as i64),
+    }
+}
+
 /// Display `Duration` as human readable time
 pub trait Humanize {
     fn humanize(&self) -> String;
@@ -12,6 +12,64 @@
 //!
 //! ```
 //!
+mod age;
+mod batch;
+mod business;
+mod calendar;
+#[cfg(feature = "capi")]
+mod capi;
+mod eta;
+mod format;
+mod html;
 mod humantime;
+mod locale;
+mod parse;
+#[cfg(feature = "pyo3")]
+mod python;
+mod range;
+mod rate;
+mod stats;
+mod stream;
+#[cfg(feature = "tokio")]
+mod ticker;
+#[cfg(feature = "tracing")]
+mod trace;
+#[cfg(feature = "tz")]
+mod tz;
+#[cfg(feature = "clap")]
+mod value_parser;
+#[cfg(feature = "wasm")]
+mod wasm;
 
-pub use crate::humantime::{Accuracy, HumanTime, Humanize, Tense};
+pub use crate::age::{Age, AgeFormat};
+pub use crate::batch::{humanize_batch, BatchOptions};
+pub use crate::business::{BusinessCalendar, HolidayError};
+pub use crate::calendar::{CalendarPeriod, Weekday};
+#[cfg(feature = "capi")]
+pub use crate::capi::{
+    time_humanize_format, time_humanize_parse, TIME_HUMANIZE_PRECISE, TIME_HUMANIZE_PRESENT,
+    TIME_HUMANIZE_WORDS,
+};
+pub use crate::eta::Eta;
+pub use crate::format::{
+    Clock, FormatOptions, Formatted, ListFormat, NearZero, NumberStyle, Style,
+};
+pub use crate::humantime::{Accuracy, HumanTime, Humanize, Tense, Unit};
+pub use crate::locale::{English, Locale};
+pub use crate::parse::ParseError;
+#[cfg(feature = "pyo3")]
+pub use crate::python::PyHumanTime;
+pub use crate::range::{HumanRange, RangeStyle, RangeText};
+pub use crate::rate::{Frequency, HumanInterval, HumanRate};
+pub use crate::stats::{HumanStats, Stat};
+pub use crate::stream::{humanize_stream, humanize_text, StreamMode, StreamOptions};
+#[cfg(feature = "tokio")]
+pub use crate::ticker::RelativeTimeTicker;
+#[cfg(feature = "tracing")]
+pub use crate::trace::{HumanDuration, HumanDurationFields, HumanDurationVisitor};
+#[cfg(feature = "tz")]
+pub use crate::tz::{TimeZone, TzError};
+#[cfg(feature = "clap")]
+pub use crate::value_parser::{DurationValueParser, HumanTimeValueParser};
+#[cfg(feature = "wasm")]
+pub use crate::wasm::{wasm_humanize, wasm_parse};
@@ -0,0 +1,266 @@
+use crate::calendar::{CalendarPeriod, Weekday};
+use crate::format::ListFormat;
+use crate::humantime::{Tense, Unit};
+use crate::range::{RangeStyle, RangeText};
+use crate::rate::Frequency;
+
+/// Provides the words used when rendering a `HumanTime` in a given language
+///
+/// Every method has an English default, so a locale only needs to override the
+/// phrases it wants to change.
+pub trait Locale: Sync {
+    /// Name of the given day of the week
+    fn weekday(&self, weekday: Weekday) -> String {
+        match weekday {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+        .into()
+    }
+
+    /// Name of `unit`, in plural form if `plural` is set
+    fn unit_name(&self, unit: Unit, plural: bool) -> String {
+        let name = match unit {
+            Unit::Nanosecond => return "ns".into(),
+            Unit::Microsecond => return "µs".into(),
+            Unit::Millisecond => return "ms".into(),
+            Unit::Second => "second",
+            Unit::Minute => "minute",
+            Unit::Hour => "hour",
+            Unit::Day => "day",
+            Unit::Week => "week",
+            Unit::Month => "month",
+            Unit::Year => "year",
+        };
+
+        if plural {
+            format!("{}s", name)
+        } else {
+            name.into()
+        }
+    }
+
+    /// Name of a single `unit` in rough text, with its article, e.g. "an hour"
+    fn single_unit(&self, unit: Unit) -> String {
+        let article = if unit == Unit::Hour { "an" } else { "a" };
+        format!("{} {}", article, self.unit_name(unit, false))
+    }
+
+    /// Text for the present moment, "now" in English
+    fn now(&self) -> String {
+        "now".into()
+    }
+
+    /// Text for `text` in `tense`, e.g. "3 hours ago" or "in 3 hours"
+    fn with_tense(&self, text: &str, tense: Tense) -> String {
+        match tense {
+            Tense::Past => format!("{} ago", text),
+            Tense::Future => format!("in {}", text),
+            Tense::Present => text.into(),
+        }
+    }
+
+    /// Symbol of `unit` written right after a number, e.g. the "s" in "2.3s"
+    fn unit_symbol(&self, unit: Unit) -> String {
+        match unit {
+            Unit::Nanosecond => "ns",
+            Unit::Microsecond => "µs",
+            Unit::Millisecond => "ms",
+            Unit::Second => "s",
+            Unit::Minute => "m",
+            Unit::Hour => "h",
+            Unit::Day => "d",
+            Unit::Week => "w",
+            Unit::Month => "mo",
+            Unit::Year => "y",
+        }
+        .into()
+    }
+
+    /// Separator between the integer and fractional part of a decimal number
+    fn decimal_separator(&self) -> &str {
+        "."
+    }
+
+    /// How lists of units are joined, e.g. "1 hour, 2 minutes and 3 seconds"
+    fn list_format(&self) -> ListFormat {
+        ListFormat::default()
+    }
+
+    /// Spelled out form of `n`, e.g. "forty-five"
+    fn spell_number(&self, n: u64) -> String {
+        english_number(n)
+    }
+
+    /// Text for a calendar-relative period, e.g. "yesterday" or "next week"
+    fn calendar(&self, period: CalendarPeriod) -> String {
+        match period {
+            CalendarPeriod::Today { hour, minute } => format!("today at {:02}:{:02}", hour, minute),
+            CalendarPeriod::Yesterday => "yesterday".into(),
+            CalendarPeriod::Tomorrow => "tomorrow".into(),
+            CalendarPeriod::LastWeekday(day) => format!("last {}", self.weekday(day)),
+            CalendarPeriod::NextWeekday(day) => format!("next {}", self.weekday(day)),
+            CalendarPeriod::LastWeek => "last week".into(),
+            CalendarPeriod::NextWeek => "next week".into(),
+            CalendarPeriod::LastMonth => "last month".into(),
+            CalendarPeriod::NextMonth => "next month".into(),
+            CalendarPeriod::LastYear => "last year".into(),
+            CalendarPeriod::NextYear => "next year".into(),
+            CalendarPeriod::Relative(ht) => format!("{}", ht),
+        }
+    }
+
+    /// Text for how often something happens, e.g. "every 2 days" or "twice a week"
+    fn frequency(&self, frequency: Frequency<'_>) -> String {
+        let once = |times: &str, unit: Unit| format!("{} {}", times, self.single_unit(unit));
+
+        match frequency {
+            Frequency::Every { count: None, unit } => {
+                format!("every {}", self.unit_name(unit, false))
+            }
+            Frequency::Every {
+                count: Some(count),
+                unit,
+            } => format!("every {} {}", count, self.unit_name(unit, true)),
+            Frequency::EveryInterval(interval) => format!("every {}", interval),
+            Frequency::Times {
+                times,
+                count,
+                unit,
+                approximate,
+            } => {
+                let text = match times {
+                    1 => once("once", unit),
+                    2 => once("twice", unit),
+                    _ if approximate => format!("{} per {}", count, self.unit_name(unit, false)),
+                    _ => format!("{} times per {}", count, self.unit_name(unit, false)),
+                };
+                match approximate {
+                    true => format!("about {}", text),
+                    false => text,
+                }
+            }
+            Frequency::Per { count, unit } => {
+                format!("{} per {}", count, self.unit_name(unit, false))
+            }
+            Frequency::Continuously => "continuously".into(),
+            Frequency::Never => "never".into(),
+        }
+    }
+
+    /// Text for an age like "3 years", e.g. "3 years old"
+    fn age(&self, age: &str) -> String {
+        format!("{} old", age)
+    }
+
+    /// Text for a range of durations in `tense`, e.g. "in 2–3 hours" or "up to 5 minutes"
+    fn range(&self, range: RangeText<'_>, style: RangeStyle, tense: Tense) -> String {
+        let text = match (range, style) {
+            (RangeText::SameUnit { min, max, unit }, RangeStyle::Dash) => {
+                format!("{}–{} {}", min, max, self.unit_name(unit, true))
+            }
+            (RangeText::SameUnit { min, max, unit }, RangeStyle::Words) => {
+                format!("between {} and {} {}", min, max, self.unit_name(unit, true))
+            }
+            (RangeText::Between { min, max }, RangeStyle::Dash) => format!("{} to {}", min, max),
+            (RangeText::Between { min, max }, RangeStyle::Words) => {
+                format!("between {} and {}", min, max)
+            }
+            (RangeText::AtMost(max), _) if tense == Tense::Future => {
+                return format!("within {}", max)
+            }
+            (RangeText::AtMost(max), _) => format!("up to {}", max),
+            (RangeText::AtLeast(min), _) => format!("at least {}", min),
+        };
+
+        self.with_tense(&text, tense)
+    }
+}
+
+/// The default English locale
+#[derive(Clone, Copy, Debug, Default)]
+pub struct English;
+
+impl Locale for English {}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [(u64, &str); 6] = [
+    (1_000_000_000_000_000_000, "quintillion"),
+    (1_000_000_000_000_000, "quadrillion"),
+    (1_000_000_000_000, "trillion"),
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+/// Spell out `n` in English words, e.g. "one hundred twenty-three"
+fn english_number(n: u64) -> String {
+    if n < 1000 {
+        return english_below_thousand(n);
+    }
+
+    let mut words = vec![];
+    let mut rest = n;
+    for &(scale, name) in SCALES.iter() {
+        if rest >= scale {
+            words.push(format!("{} {}", english_below_thousand(rest / scale), name));
+            rest %= scale;
+        }
+    }
+
+    if rest > 0 {
+        words.push(english_below_thousand(rest));
+    }
+
+    words.join(" ")
+}
+
+fn english_below_thousand(n: u64) -> String {
+    let (hundreds, tens, ones) = (
+        (n / 100) as usize,
+        (n % 100 / 10) as usize,
+        (n % 10) as usize,
+    );
+    match (hundreds, tens, ones) {
+        (0, 0..=1, _) => ONES[n as usize].into(),
+        (0, _, 0) => TENS[tens].into(),
+        (0, _, _) => format!("{}-{}", TENS[tens], ONES[ones]),
+        (_, 0, 0) => format!("{} hundred", ONES[hundreds]),
+        _ => format!(
+            "{} hundred {}",
+            ONES[hundreds],
+            english_below_thousand(n % 100)
+        ),
+    }
+}
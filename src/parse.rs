@@ -0,0 +1,306 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::humantime::{HumanTime, Unit};
+
+/// Error returned when text cannot be parsed into a `HumanTime`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The text contains no duration
+    Empty,
+    /// A number could not be read, e.g. "1.2.3 hours"
+    InvalidNumber(String),
+    /// A number is not followed by a unit, e.g. "5"
+    MissingUnit(String),
+    /// A word is not a known unit, e.g. "5 fortnights"
+    UnknownUnit(String),
+    /// The duration is too long to be represented
+    Overflow,
+    /// A sign is not at the start or comes with "in" or "ago", e.g. "in -3h"
+    MisplacedSign(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no duration given"),
+            Self::InvalidNumber(number) => write!(f, "invalid number \"{}\"", number),
+            Self::MissingUnit(number) => write!(
+                f,
+                "missing unit after \"{0}\", e.g. \"{0}s\" or \"{0} minutes\"",
+                number
+            ),
+            Self::UnknownUnit(unit) => match suggest_unit(unit) {
+                Some(name) => write!(f, "unknown unit \"{}\", did you mean \"{}\"?", unit, name),
+                None => write!(f, "unknown unit \"{}\"", unit),
+            },
+            Self::Overflow => write!(f, "duration is too long"),
+            Self::MisplacedSign(text) => write!(
+                f,
+                "misplaced sign in \"{}\", only a leading \"-\" or \"+\" without \"in\" or \"ago\" is allowed",
+                text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Unit names suggested for misspelled units
+const UNIT_NAMES: [&str; 20] = [
+    "ns",
+    "µs",
+    "ms",
+    "s",
+    "m",
+    "h",
+    "d",
+    "w",
+    "y",
+    "nanoseconds",
+    "microseconds",
+    "milliseconds",
+    "seconds",
+    "minutes",
+    "hours",
+    "days",
+    "weeks",
+    "months",
+    "years",
+    "mo",
+];
+
+/// The unit name closest to the misspelled `unit`, if any is close enough
+fn suggest_unit(unit: &str) -> Option<&'static str> {
+    let unit = unit.to_lowercase();
+    UNIT_NAMES
+        .iter()
+        .map(|name| (edit_distance(&unit, name), *name))
+        .filter(|&(distance, name)| distance <= 2 && distance < name.chars().count())
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, name)| name)
+}
+
+/// Levenshtein distance between `a` and `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Parse unsigned clock text like "01:30:00", "05:00" or "1:02:03.5"
+fn parse_clock(text: &str) -> Result<HumanTime, ParseError> {
+    let fields: Vec<&str> = text.split(':').collect();
+    let units: &[Unit] = match fields.len() {
+        2 => &[Unit::Minute, Unit::Second],
+        3 => &[Unit::Hour, Unit::Minute, Unit::Second],
+        _ => return Err(ParseError::InvalidNumber(text.into())),
+    };
+
+    let mut total: u128 = 0;
+    for (field, &unit) in fields.iter().zip(units) {
+        // Only the seconds may have a fraction
+        if field.is_empty() || (unit != Unit::Second && field.contains('.')) {
+            return Err(ParseError::InvalidNumber((*field).into()));
+        }
+        total = nanos(field, unit)?
+            .checked_add(total)
+            .ok_or(ParseError::Overflow)?;
+    }
+
+    from_nanos(total)
+}
+
+/// Positive `HumanTime` of `total` nanoseconds
+fn from_nanos(total: u128) -> Result<HumanTime, ParseError> {
+    let secs = u64::try_from(total / 1_000_000_000).map_err(|_| ParseError::Overflow)?;
+    Ok(HumanTime::from(Duration::new(
+        secs,
+        (total % 1_000_000_000) as u32,
+    )))
+}
+
+impl FromStr for Unit {
+    type Err = ParseError;
+
+    /// Parse a unit from its English name or abbreviation, e.g. "hours" or "h"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unit = match s.to_lowercase().as_str() {
+            "ns" | "nanosecond" | "nanoseconds" | "nanos" => Unit::Nanosecond,
+            "us" | "µs" | "μs" | "microsecond" | "microseconds" | "micros" => Unit::Microsecond,
+            "ms" | "millisecond" | "milliseconds" | "millis" => Unit::Millisecond,
+            "s" | "sec" | "secs" | "second" | "seconds" => Unit::Second,
+            "m" | "min" | "mins" | "minute" | "minutes" => Unit::Minute,
+            "h" | "hr" | "hrs" | "hour" | "hours" => Unit::Hour,
+            "d" | "day" | "days" => Unit::Day,
+            "w" | "wk" | "wks" | "week" | "weeks" => Unit::Week,
+            "mo" | "month" | "months" => Unit::Month,
+            "y" | "yr" | "yrs" | "year" | "years" => Unit::Year,
+            _ => return Err(ParseError::UnknownUnit(s.into())),
+        };
+
+        Ok(unit)
+    }
+}
+
+/// Split `text` into runs of digits and runs of letters, dropping everything else
+fn tokens(text: &str) -> Vec<&str> {
+    #[derive(PartialEq)]
+    enum Class {
+        Number,
+        Word,
+        Other,
+    }
+
+    let class = |c: char| match c {
+        _ if c.is_ascii_digit() || c == '.' => Class::Number,
+        _ if c.is_alphabetic() => Class::Word,
+        _ => Class::Other,
+    };
+
+    let mut tokens = vec![];
+    let mut start = 0;
+    let mut current = Class::Other;
+
+    for (i, c) in text.char_indices() {
+        let next = class(c);
+        if next != current {
+            if current != Class::Other {
+                tokens.push(&text[start..i]);
+            }
+            start = i;
+            current = next;
+        }
+    }
+
+    if current != Class::Other {
+        tokens.push(&text[start..]);
+    }
+
+    tokens
+}
+
+/// Exact number of nanoseconds in `number` units, where `number` may have a fraction
+fn nanos(number: &str, unit: Unit) -> Result<u128, ParseError> {
+    let invalid = || ParseError::InvalidNumber(number.into());
+    let (whole, fraction) = match number.find('.') {
+        Some(dot) => (&number[..dot], &number[dot + 1..]),
+        None => (number, ""),
+    };
+
+    let digits = |digits: &str| -> Result<u128, ParseError> {
+        match digits {
+            "" => Ok(0),
+            _ if digits.chars().all(|c| c.is_ascii_digit()) => {
+                digits.parse().map_err(|_| ParseError::Overflow)
+            }
+            _ => Err(invalid()),
+        }
+    };
+
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid());
+    }
+
+    // Digits beyond the nanosecond precision of a year are dropped
+    let fraction = &fraction[..fraction.len().min(18)];
+    let scale = 10u128.pow(fraction.len() as u32);
+
+    let whole = digits(whole)?.checked_mul(unit.as_nanos());
+    let fraction = digits(fraction)? * unit.as_nanos() / scale;
+
+    whole
+        .and_then(|whole| whole.checked_add(fraction))
+        .ok_or(ParseError::Overflow)
+}
+
+impl FromStr for HumanTime {
+    type Err = ParseError;
+
+    /// Parse English text like "in 2 days", "1 hour and 30 minutes ago", "1h30m"
+    /// or clock text like "01:30:00"
+    ///
+    /// A leading "-" gives a negative `HumanTime` like "ago" does, a leading "+"
+    /// or text without sign, "in" or "ago" a positive one. A sign anywhere else
+    /// or together with "in" or "ago" is an error.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim().to_lowercase();
+        if matches!(text.as_str(), "now" | "just now" | "in a moment") {
+            return Ok(HumanTime::now());
+        }
+
+        let (text, sign) = match text.chars().next() {
+            Some(sign @ '-') | Some(sign @ '+') => (&text[1..], Some(sign == '-')),
+            _ => (text.as_str(), None),
+        };
+        if text.contains(['-', '+'].as_ref()) {
+            return Err(ParseError::MisplacedSign(s.trim().into()));
+        }
+
+        if text.contains(':') {
+            let ht = parse_clock(text)?;
+            return Ok(if sign == Some(true) { -ht } else { ht });
+        }
+
+        let (text, tense) = match text.strip_suffix("ago") {
+            Some(rest) => (rest, Some(true)),
+            None => match text.strip_prefix("in") {
+                Some(rest) if rest.is_empty() || rest.starts_with(char::is_whitespace) => {
+                    (rest, Some(false))
+                }
+                _ => (text, None),
+            },
+        };
+        let is_past = match (sign, tense) {
+            (Some(_), Some(_)) => return Err(ParseError::MisplacedSign(s.trim().into())),
+            (Some(is_past), None) | (None, Some(is_past)) => is_past,
+            (None, None) => false,
+        };
+
+        let mut total: u128 = 0;
+        let mut tokens = tokens(text)
+            .into_iter()
+            .filter(|token| *token != "and")
+            .peekable();
+
+        if tokens.peek().is_none() {
+            return Err(ParseError::Empty);
+        }
+
+        while let Some(number) = tokens.next() {
+            let number = match number {
+                "a" | "an" => "1",
+                _ if number.starts_with(|c: char| c.is_ascii_digit() || c == '.') => number,
+                _ => return Err(ParseError::InvalidNumber(number.into())),
+            };
+
+            let unit: Unit = tokens
+                .next()
+                .ok_or_else(|| ParseError::MissingUnit(number.into()))?
+                .parse()?;
+
+            total = nanos(number, unit)?
+                .checked_add(total)
+                .ok_or(ParseError::Overflow)?;
+        }
+
+        let ht = from_nanos(total)?;
+
+        Ok(if is_past { -ht } else { ht })
+    }
+}
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDelta, PyDeltaAccess};
+
+use crate::format::FormatOptions;
+use crate::humantime::{Accuracy, HumanTime, Tense};
+
+/// `HumanTime` for Python, in a module built with `maturin develop`
+///
+/// ```python
+/// >>> from time_humanize import HumanTime
+/// >>> str(HumanTime.from_seconds(-5400))
+/// 'an hour ago'
+/// >>> HumanTime.from_seconds(5400).format(precise=True)
+/// 'in 1 hour and 30 minutes'
+/// ```
+#[pyclass(name = "HumanTime", module = "time_humanize", frozen, eq)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct PyHumanTime(HumanTime);
+
+#[pymethods]
+impl PyHumanTime {
+    /// Duration of `seconds` from now, negative for the past
+    #[staticmethod]
+    fn from_seconds(seconds: f64) -> PyResult<Self> {
+        if !seconds.is_finite() {
+            return Err(PyValueError::new_err("seconds must be a finite number"));
+        }
+        let magnitude = Duration::try_from_secs_f64(seconds.abs())
+            .map_err(|_| PyValueError::new_err("seconds are out of range"))?;
+        match seconds < 0.0 {
+            true => Ok(Self(-HumanTime::from(magnitude))),
+            false => Ok(Self(HumanTime::from(magnitude))),
+        }
+    }
+
+    /// Duration of a `datetime.timedelta` from now, negative for the past
+    #[staticmethod]
+    fn from_timedelta(delta: &Bound<'_, PyDelta>) -> Self {
+        // Only the days of a timedelta are negative, the seconds and microseconds never are
+        let micros = i128::from(delta.get_days()) * 86_400_000_000
+            + i128::from(delta.get_seconds()) * 1_000_000
+            + i128::from(delta.get_microseconds());
+        // At most 999999999 days, which fit into the seconds of a `Duration`
+        let (seconds, micros_left) = (micros.abs() / 1_000_000, micros.abs() % 1_000_000);
+        let magnitude = Duration::new(seconds as u64, micros_left as u32 * 1_000);
+        match micros < 0 {
+            true => Self(-HumanTime::from(magnitude)),
+            false => Self(HumanTime::from(magnitude)),
+        }
+    }
+
+    /// Parses text like "in 2 hours", "5 minutes ago" or "1h30m", raising `ValueError` for other text
+    #[staticmethod]
+    fn parse(text: &str) -> PyResult<Self> {
+        text.parse()
+            .map(Self)
+            .map_err(|err| PyValueError::new_err(format!("{}", err)))
+    }
+
+    /// Text of this duration, precise like "in 1 hour and 30 minutes" or rough like "in an hour"
+    ///
+    /// `tense` is "past", "present" or "future", by default the tense of the duration.
+    #[pyo3(signature = (*, precise = false, tense = None))]
+    fn format(&self, precise: bool, tense: Option<&str>) -> PyResult<String> {
+        let accuracy = match precise {
+            true => Accuracy::Precise,
+            false => Accuracy::Rough,
+        };
+        let tense = match tense {
+            None => self.0.tense(accuracy),
+            Some("past") => Tense::Past,
+            Some("present") => Tense::Present,
+            Some("future") => Tense::Future,
+            Some(other) => {
+                return Err(PyValueError::new_err(format!(
+                    "tense must be \"past\", \"present\" or \"future\", not \"{}\"",
+                    other
+                )))
+            }
+        };
+
+        Ok(self.0.to_text(accuracy, tense, &FormatOptions::default()))
+    }
+
+    /// Seconds of this duration, negative for the past, like `timedelta.total_seconds`
+    fn total_seconds(&self) -> f64 {
+        let seconds = self.0.duration().as_secs_f64();
+        match self.0.is_negative() {
+            true => -seconds,
+            false => seconds,
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("HumanTime.from_seconds({:?})", self.total_seconds())
+    }
+}
+
+/// The `time_humanize` Python module
+#[pymodule]
+fn time_humanize(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyHumanTime>()
+}
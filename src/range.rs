@@ -0,0 +1,217 @@
+use std::fmt;
+
+use crate::format::{FormatOptions, Style};
+use crate::humantime::{Accuracy, HumanTime, Tense, Unit};
+
+/// How both ends of a `HumanRange` are joined
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum RangeStyle {
+    /// "2–3 hours" or "1 hour to 90 minutes"
+    Dash,
+    /// "between 2 and 3 hours" or "between 1 hour and 90 minutes"
+    Words,
+}
+
+/// A range of durations, as passed to `Locale::range`
+///
+/// The ends are already rendered without tense.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RangeText<'a> {
+    /// Both ends are counts of the same unit, e.g. "2–3 hours"
+    SameUnit {
+        min: &'a str,
+        max: &'a str,
+        unit: Unit,
+    },
+    /// The ends differ in their units, e.g. "1 hour to 90 minutes"
+    ///
+    /// Ends on both sides of now are rendered with their own tense, the earlier
+    /// one first, e.g. "5 minutes ago to in 10 minutes", and the range itself
+    /// takes `Tense::Present`.
+    Between { min: &'a str, max: &'a str },
+    /// Only an upper end, e.g. "up to 5 minutes"
+    AtMost(&'a str),
+    /// Only a lower end, e.g. "at least 2 days"
+    AtLeast(&'a str),
+}
+
+/// A range between two durations, e.g. "2–3 hours" or "in at least 2 days"
+///
+/// Ends counting the same unit share it, "2–3 hours" instead of "2 hours to 3
+/// hours". A range starting at zero is open, "up to 5 minutes". A range from the
+/// past into the future gives each end its own tense.
+///
+/// ```
+/// use time_humanize::{HumanRange, HumanTime, RangeStyle};
+///
+/// let range = HumanRange::new(HumanTime::from_hours(2), HumanTime::from_hours(3));
+/// assert_eq!("in 2–3 hours", range.to_string());
+///
+/// let range = HumanRange::new(HumanTime::from_minutes(-5), HumanTime::from_minutes(-10));
+/// assert_eq!("between 5 and 10 minutes ago", range.style(RangeStyle::Words).to_string());
+///
+/// let range = HumanRange::at_least(HumanTime::from_days(2));
+/// assert_eq!("in at least 2 days", range.to_string());
+///
+/// let range = HumanRange::new(HumanTime::from_minutes(-5), HumanTime::from_minutes(10));
+/// assert_eq!("5 minutes ago to in 10 minutes", range.to_string());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanRange {
+    min: Option<HumanTime>,
+    max: Option<HumanTime>,
+    style: RangeStyle,
+}
+
+impl HumanRange {
+    /// Range between `a` and `b`, in either order
+    pub fn new(a: HumanTime, b: HumanTime) -> Self {
+        let (min, max) = if a.duration() <= b.duration() {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        Self {
+            min: Some(min),
+            max: Some(max),
+            style: RangeStyle::Dash,
+        }
+    }
+
+    /// Range of `max` or less, "up to 5 minutes"
+    pub fn at_most(max: HumanTime) -> Self {
+        Self {
+            min: None,
+            max: Some(max),
+            style: RangeStyle::Dash,
+        }
+    }
+
+    /// Range of `min` or more, "at least 2 days"
+    pub fn at_least(min: HumanTime) -> Self {
+        Self {
+            min: Some(min),
+            max: None,
+            style: RangeStyle::Dash,
+        }
+    }
+
+    /// Join both ends with `style`
+    #[must_use]
+    pub fn style(mut self, style: RangeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The shorter end, `None` for ranges created with `at_most`
+    pub fn min(self) -> Option<HumanTime> {
+        self.min
+    }
+
+    /// The longer end, `None` for ranges created with `at_least`
+    pub fn max(self) -> Option<HumanTime> {
+        self.max
+    }
+
+    /// Tense of the end farther from now
+    fn tense(self, accuracy: Accuracy) -> Tense {
+        self.max
+            .or(self.min)
+            .map_or(Tense::Present, |end| end.tense(accuracy))
+    }
+
+    /// Gives English text representation with given `accuracy` and `tense`
+    #[must_use]
+    pub fn to_text_en(self, accuracy: Accuracy, tense: Tense) -> String {
+        self.to_text(accuracy, tense, &FormatOptions::default())
+    }
+
+    /// Gives text representation with given `accuracy`, `tense` and `options`
+    #[must_use]
+    pub fn to_text(self, accuracy: Accuracy, tense: Tense, options: &FormatOptions) -> String {
+        let text = |end: HumanTime| end.to_text(accuracy, Tense::Present, options);
+
+        let (min, max) = match (self.min, self.max) {
+            // A range starting at "now" has no lower end worth mentioning
+            (Some(min), Some(max))
+                if min.is_zero()
+                    || (min.is_near_zero(accuracy) && options.style == Style::Units) =>
+            {
+                (None, max)
+            }
+            (Some(min), Some(max)) => (Some(min), max),
+            (Some(min), None) => {
+                let range = RangeText::AtLeast(&text(min));
+                return options.locale.range(range, self.style, tense);
+            }
+            (None, Some(max)) => (None, max),
+            (None, None) => unreachable!("a range has at least one end"),
+        };
+
+        // A range shorter than "now" is just "now"
+        if max.is_near_zero(accuracy) && options.style == Style::Units {
+            return max.to_text(accuracy, tense, options);
+        }
+
+        let min = match min {
+            Some(min) => min,
+            None => {
+                return options
+                    .locale
+                    .range(RangeText::AtMost(&text(max)), self.style, tense)
+            }
+        };
+
+        if min.is_negative() != max.is_negative() {
+            let (earlier, later) = match min.is_negative() {
+                true => (min, max),
+                false => (max, min),
+            };
+            let text = |end: HumanTime| end.to_text(accuracy, end.tense(accuracy), options);
+            let range = RangeText::Between {
+                min: &text(earlier),
+                max: &text(later),
+            };
+            return options.locale.range(range, self.style, Tense::Present);
+        }
+
+        let (min_text, max_text) = (text(min), text(max));
+        if min_text == max_text {
+            return max.to_text(accuracy, tense, options);
+        }
+
+        let counts = match (min.single_unit(accuracy), max.single_unit(accuracy)) {
+            (Some((min_unit, min)), Some((max_unit, max))) if min_unit == max_unit => {
+                Some((options.number(min), options.number(max), max_unit))
+            }
+            _ => None,
+        };
+
+        let range = match &counts {
+            Some((min, max, unit)) if options.style == Style::Units => RangeText::SameUnit {
+                min,
+                max,
+                unit: *unit,
+            },
+            _ => RangeText::Between {
+                min: &min_text,
+                max: &max_text,
+            },
+        };
+
+        options.locale.range(range, self.style, tense)
+    }
+}
+
+impl fmt::Display for HumanRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let accuracy = if f.alternate() {
+            Accuracy::Precise
+        } else {
+            Accuracy::Rough
+        };
+
+        f.pad(&self.to_text_en(accuracy, self.tense(accuracy)))
+    }
+}
@@ -0,0 +1,316 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::format::FormatOptions;
+use crate::humantime::{Accuracy, HumanTime, Tense, Unit};
+
+/// Units a frequency is expressed in, from the smallest to the largest
+const UNITS: [Unit; 7] = [
+    Unit::Second,
+    Unit::Minute,
+    Unit::Hour,
+    Unit::Day,
+    Unit::Week,
+    Unit::Month,
+    Unit::Year,
+];
+
+/// Largest count of "3 times per week" used for intervals that are no whole number of units
+const MAX_TIMES: u64 = 4;
+
+/// How often something happens, as passed to `Locale::frequency`
+///
+/// Counts are already formatted with the `NumberStyle` in use.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Frequency<'a> {
+    /// Once per `count` units, e.g. "every 2 days", `count` is `None` for a single unit
+    Every { count: Option<&'a str>, unit: Unit },
+    /// Once per interval spanning several units, e.g. "every 1 hour and 30 minutes"
+    EveryInterval(&'a str),
+    /// A whole number of times per unit, e.g. "twice a week" or "about 3 per minute"
+    Times {
+        times: u64,
+        count: &'a str,
+        unit: Unit,
+        approximate: bool,
+    },
+    /// A fractional number per unit, e.g. "2.5 per minute"
+    Per { count: &'a str, unit: Unit },
+    /// Without a pause between occurrences
+    Continuously,
+    /// Not at all
+    Never,
+}
+
+/// How often something happens, given as the time between two occurrences
+///
+/// A whole number of a single unit is preferred, "every 2 days" or "every hour".
+/// Intervals that split the next larger unit evenly read like "twice a week",
+/// anything else falls back to the duration itself.
+///
+/// ```
+/// use std::time::Duration;
+/// use time_humanize::HumanInterval;
+///
+/// let interval = HumanInterval::from(Duration::from_secs(2 * 86400));
+/// assert_eq!("every 2 days", interval.to_string());
+///
+/// let interval = HumanInterval::from(Duration::from_secs(84 * 3600));
+/// assert_eq!("twice a week", interval.to_string());
+///
+/// let interval = HumanInterval::from(Duration::from_secs(5400));
+/// assert_eq!("every hour", format!("{}", interval));
+/// assert_eq!("every 1 hour and 30 minutes", format!("{:#}", interval));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanInterval {
+    interval: Duration,
+}
+
+impl HumanInterval {
+    /// Something happening once per `interval`
+    pub fn new(interval: Duration) -> Self {
+        Self { interval }
+    }
+
+    /// Time between two occurrences
+    pub fn interval(self) -> Duration {
+        self.interval
+    }
+
+    /// The same frequency as occurrences per time
+    pub fn to_rate(self) -> HumanRate {
+        HumanRate::per_second(1.0 / self.interval.as_secs_f64())
+    }
+
+    /// Gives English text representation with given `accuracy`
+    #[must_use]
+    pub fn to_text_en(self, accuracy: Accuracy) -> String {
+        self.to_text(accuracy, &FormatOptions::default())
+    }
+
+    /// Gives text representation with given `accuracy` and `options`
+    #[must_use]
+    pub fn to_text(self, accuracy: Accuracy, options: &FormatOptions) -> String {
+        let nanos = self.interval.as_nanos();
+        if nanos == 0 {
+            return options.locale.frequency(Frequency::Continuously);
+        }
+        // Several times per second read better as a rate
+        if nanos < Unit::Second.as_nanos() {
+            return self.to_rate().to_text(accuracy, options);
+        }
+
+        let unit = UNITS
+            .iter()
+            .rev()
+            .copied()
+            .find(|unit| nanos >= unit.as_nanos())
+            .unwrap_or(Unit::Second);
+        if let (count, 0) = (nanos / unit.as_nanos(), nanos % unit.as_nanos()) {
+            return every(count as u64, unit, options);
+        }
+
+        if let Some(text) = self.times_per_next(unit, accuracy, options) {
+            return text;
+        }
+
+        match accuracy {
+            Accuracy::Rough => {
+                let (unit, count) = HumanTime::from(self.interval).rough_unit();
+                every(count, unit, options)
+            }
+            Accuracy::Precise => {
+                let text = HumanTime::from(self.interval).to_text(
+                    Accuracy::Precise,
+                    Tense::Present,
+                    options,
+                );
+                options.locale.frequency(Frequency::EveryInterval(&text))
+            }
+        }
+    }
+
+    /// "twice a week" if the unit after `unit` is a small multiple of the interval
+    ///
+    /// Precise text needs an exact multiple, rough text one within 5%.
+    fn times_per_next(
+        self,
+        unit: Unit,
+        accuracy: Accuracy,
+        options: &FormatOptions,
+    ) -> Option<String> {
+        let next = *UNITS.get(UNITS.iter().position(|u| *u == unit)? + 1)?;
+        let ratio = next.as_nanos() as f64 / self.interval.as_nanos() as f64;
+        let times = ratio.round();
+        let approximate = (ratio - times).abs() > f64::EPSILON * ratio;
+
+        let close_enough = match accuracy {
+            Accuracy::Rough => (ratio - times).abs() <= 0.05 * times,
+            Accuracy::Precise => !approximate,
+        };
+        if !close_enough || times < 2.0 || times > MAX_TIMES as f64 {
+            return None;
+        }
+
+        let times = times as u64;
+        Some(options.locale.frequency(Frequency::Times {
+            times,
+            count: &options.number(times),
+            unit: next,
+            approximate,
+        }))
+    }
+}
+
+impl From<Duration> for HumanInterval {
+    fn from(interval: Duration) -> Self {
+        Self::new(interval)
+    }
+}
+
+impl fmt::Display for HumanInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let accuracy = if f.alternate() {
+            Accuracy::Precise
+        } else {
+            Accuracy::Rough
+        };
+
+        f.pad(&self.to_text_en(accuracy))
+    }
+}
+
+/// How often something happens, given as a number of occurrences per time
+///
+/// The smallest unit with at least one occurrence is picked, "3 times per hour"
+/// or "once a day". Rough text rounds to whole counts, "about 3 per minute",
+/// precise text keeps up to two decimals. Rates of less than once a year are
+/// rendered as intervals like "every 2 years".
+///
+/// ```
+/// use std::time::Duration;
+/// use time_humanize::HumanRate;
+///
+/// let rate = HumanRate::new(3.0, Duration::from_secs(3600));
+/// assert_eq!("3 times per hour", rate.to_string());
+///
+/// let rate = HumanRate::per_second(0.048);
+/// assert_eq!("about 3 per minute", format!("{}", rate));
+/// assert_eq!("2.88 per minute", format!("{:#}", rate));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct HumanRate {
+    per_second: f64,
+}
+
+impl HumanRate {
+    /// `count` occurrences during `per`
+    pub fn new(count: f64, per: Duration) -> Self {
+        Self::per_second(count / per.as_secs_f64())
+    }
+
+    /// `rate` occurrences per second, where negative and NaN rates mean never
+    pub fn per_second(rate: f64) -> Self {
+        Self { per_second: rate }
+    }
+
+    /// Occurrences per second
+    pub fn as_per_second(self) -> f64 {
+        self.per_second
+    }
+
+    /// The same frequency as the time between two occurrences, `None` for a rate of zero
+    pub fn to_interval(self) -> Option<HumanInterval> {
+        match self.per_second {
+            rate if rate > 0.0 => Some(interval_of(rate)),
+            _ => None,
+        }
+    }
+
+    /// Gives English text representation with given `accuracy`
+    #[must_use]
+    pub fn to_text_en(self, accuracy: Accuracy) -> String {
+        self.to_text(accuracy, &FormatOptions::default())
+    }
+
+    /// Gives text representation with given `accuracy` and `options`
+    #[must_use]
+    pub fn to_text(self, accuracy: Accuracy, options: &FormatOptions) -> String {
+        let rate = self.per_second;
+        if rate.is_nan() || rate <= 0.0 {
+            return options.locale.frequency(Frequency::Never);
+        }
+        if rate.is_infinite() {
+            return options.locale.frequency(Frequency::Continuously);
+        }
+
+        // Rough text may round up to once per unit
+        let at_least = match accuracy {
+            Accuracy::Rough => 0.9,
+            Accuracy::Precise => 1.0 - 1e-9,
+        };
+        let per_unit = |unit: Unit| rate * unit.as_nanos() as f64 / 1e9;
+        let unit = match UNITS
+            .iter()
+            .copied()
+            .find(|&unit| per_unit(unit) >= at_least)
+        {
+            Some(unit) => unit,
+            // Less than once a year
+            None => return interval_of(rate).to_text(accuracy, options),
+        };
+
+        let count = per_unit(unit);
+        let times = count.round();
+        let exact = (count - times).abs() <= 1e-9 * times.max(1.0);
+
+        if accuracy.is_rough() || exact {
+            let times = times as u64;
+            return options.locale.frequency(Frequency::Times {
+                times,
+                count: &options.number(times),
+                unit,
+                approximate: !exact,
+            });
+        }
+
+        let count = format!("{:.2}", count);
+        let count = count.trim_end_matches('0').trim_end_matches('.');
+        let count = count.replace('.', options.locale.decimal_separator());
+        options.locale.frequency(Frequency::Per {
+            count: &count,
+            unit,
+        })
+    }
+}
+
+impl fmt::Display for HumanRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let accuracy = if f.alternate() {
+            Accuracy::Precise
+        } else {
+            Accuracy::Rough
+        };
+
+        f.pad(&self.to_text_en(accuracy))
+    }
+}
+
+/// "every 2 days", or "every day" for a single unit
+fn every(count: u64, unit: Unit, options: &FormatOptions) -> String {
+    let count = match count {
+        1 => None,
+        n => Some(options.number(n)),
+    };
+
+    options.locale.frequency(Frequency::Every {
+        count: count.as_deref(),
+        unit,
+    })
+}
+
+/// Interval between occurrences at a positive `rate` per second
+fn interval_of(rate: f64) -> HumanInterval {
+    HumanInterval::new(Duration::try_from_secs_f64(1.0 / rate).unwrap_or(Duration::MAX))
+}
@@ -0,0 +1,207 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::time::Duration;
+
+use crate::format::FormatOptions;
+use crate::humantime::{HumanTime, Unit};
+
+/// A statistic of `HumanStats` shown in its summary
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Stat {
+    Min,
+    Median,
+    Mean,
+    StdDev,
+    Max,
+    /// The given percentile, e.g. 95 for "p95"
+    Percentile(u8),
+}
+
+impl Stat {
+    /// Label in summaries, e.g. "median" or "p99"
+    fn label(self) -> String {
+        match self {
+            Self::Min => "min".into(),
+            Self::Median => "median".into(),
+            Self::Mean => "mean".into(),
+            Self::StdDev => "stddev".into(),
+            Self::Max => "max".into(),
+            Self::Percentile(p) => format!("p{}", p),
+        }
+    }
+}
+
+/// Statistics of many durations, like the run times of a job
+///
+/// Percentiles interpolate linearly between the two nearest durations. All
+/// statistics of no durations at all are zero.
+///
+/// ```
+/// use std::time::Duration;
+/// use time_humanize::{HumanStats, HumanTime};
+///
+/// let stats: HumanStats = (1..=100).map(Duration::from_millis).collect();
+///
+/// assert_eq!(100, stats.count());
+/// assert_eq!(HumanTime::from(Duration::from_micros(50_500)), stats.median());
+/// assert_eq!("median 50.5ms, p99 99.0ms, max 100.0ms", stats.to_string());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HumanStats {
+    /// All durations in ascending order
+    sorted: Vec<Duration>,
+}
+
+impl HumanStats {
+    /// Statistics shown by `Display`
+    pub const SUMMARY: [Stat; 3] = [Stat::Median, Stat::Percentile(99), Stat::Max];
+
+    /// Number of durations
+    pub fn count(&self) -> usize {
+        self.sorted.len()
+    }
+
+    /// Shortest duration
+    pub fn min(&self) -> HumanTime {
+        self.percentile(0.0)
+    }
+
+    /// Longest duration
+    pub fn max(&self) -> HumanTime {
+        self.percentile(100.0)
+    }
+
+    /// Middle duration
+    pub fn median(&self) -> HumanTime {
+        self.percentile(50.0)
+    }
+
+    /// 95th percentile
+    pub fn p95(&self) -> HumanTime {
+        self.percentile(95.0)
+    }
+
+    /// 99th percentile
+    pub fn p99(&self) -> HumanTime {
+        self.percentile(99.0)
+    }
+
+    /// The duration below which `percent` of the durations fall
+    ///
+    /// # Panics
+    ///
+    /// Panics if `percent` is not in `[0, 100]`.
+    pub fn percentile(&self, percent: f64) -> HumanTime {
+        assert!(
+            (0.0..=100.0).contains(&percent),
+            "percentile must be in [0, 100]"
+        );
+
+        let last = match self.sorted.len() {
+            0 => return HumanTime::now(),
+            n => n - 1,
+        };
+        let rank = percent / 100.0 * last as f64;
+        let (lower, fraction) = (rank.floor() as usize, rank.fract());
+        let below = self.sorted[lower].as_nanos();
+        let above = self.sorted[(lower + 1).min(last)].as_nanos();
+
+        let nanos = below + ((above - below) as f64 * fraction).round() as u128;
+        from_nanos(nanos)
+    }
+
+    /// Arithmetic mean
+    pub fn mean(&self) -> HumanTime {
+        match self.sorted.len() {
+            0 => HumanTime::now(),
+            n => from_nanos(self.total_nanos() / n as u128),
+        }
+    }
+
+    /// Population standard deviation
+    pub fn stddev(&self) -> HumanTime {
+        if self.sorted.is_empty() {
+            return HumanTime::now();
+        }
+
+        let n = self.sorted.len() as f64;
+        let mean = self.total_nanos() as f64 / n;
+        let variance = self
+            .sorted
+            .iter()
+            .map(|d| (d.as_nanos() as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        from_nanos(variance.sqrt().round() as u128)
+    }
+
+    /// Value of `stat`
+    pub fn get(&self, stat: Stat) -> HumanTime {
+        match stat {
+            Stat::Min => self.min(),
+            Stat::Median => self.median(),
+            Stat::Mean => self.mean(),
+            Stat::StdDev => self.stddev(),
+            Stat::Max => self.max(),
+            Stat::Percentile(p) => self.percentile(f64::from(p.min(100))),
+        }
+    }
+
+    /// One-line summary of `stats`, e.g. "median 2.3s, p99 14.0s, max 62.0s"
+    ///
+    /// All values share the unit that fits the first statistic best, and one
+    /// decimal if that is below 100 units, so the values of summaries of similar
+    /// statistics line up in columns.
+    #[must_use]
+    pub fn summary(&self, stats: &[Stat], options: &FormatOptions) -> String {
+        let values: Vec<u128> = stats
+            .iter()
+            .map(|stat| self.get(*stat).duration().as_nanos())
+            .collect();
+
+        let typical = values.first().copied().unwrap_or(0);
+        let unit = Unit::compact_for(typical);
+        let decimals = if typical < 100 * unit.as_nanos() {
+            1
+        } else {
+            0
+        };
+
+        let texts: Vec<String> = stats
+            .iter()
+            .zip(values)
+            .map(|(stat, nanos)| {
+                let value = unit.compact_text(nanos, decimals, options);
+                format!("{} {}", stat.label(), value)
+            })
+            .collect();
+
+        texts.join(", ")
+    }
+
+    fn total_nanos(&self) -> u128 {
+        self.sorted.iter().map(Duration::as_nanos).sum()
+    }
+}
+
+impl FromIterator<Duration> for HumanStats {
+    fn from_iter<I: IntoIterator<Item = Duration>>(iter: I) -> Self {
+        let mut sorted: Vec<Duration> = iter.into_iter().collect();
+        sorted.sort_unstable();
+        Self { sorted }
+    }
+}
+
+impl fmt::Display for HumanStats {
+    /// Summary of the median, 99th percentile and maximum
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.summary(&Self::SUMMARY, &FormatOptions::default()))
+    }
+}
+
+/// Positive `HumanTime` of `nanos` nanoseconds
+fn from_nanos(nanos: u128) -> HumanTime {
+    let secs = (nanos / 1_000_000_000) as u64;
+    HumanTime::from(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
@@ -0,0 +1,269 @@
+use std::io::{self, BufRead, Write};
+use std::time::{Duration, SystemTime};
+
+use crate::calendar::{civil_from_days, days_from_civil};
+use crate::format::FormatOptions;
+use crate::humantime::{Accuracy, HumanTime, Tense, S_DAY, S_HOUR, S_MINUTE};
+
+/// What happens to a timestamp or duration recognized by `humanize_stream`
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum StreamMode {
+    /// Replace it, "took 95s" becomes "took 2 minutes"
+    Replace,
+    /// Append the humanized text, "took 95s" becomes "took 95s (2 minutes)"
+    Annotate,
+}
+
+/// Options of `humanize_stream`
+#[derive(Clone)]
+pub struct StreamOptions {
+    reference: SystemTime,
+    accuracy: Accuracy,
+    format: FormatOptions,
+    mode: StreamMode,
+}
+
+impl StreamOptions {
+    /// Replace matches with rough text, timestamps relative to `reference`
+    pub fn new(reference: SystemTime) -> Self {
+        Self {
+            reference,
+            accuracy: Accuracy::Rough,
+            format: FormatOptions::default(),
+            mode: StreamMode::Replace,
+        }
+    }
+
+    /// Render matches with `accuracy`
+    #[must_use]
+    pub fn accuracy(mut self, accuracy: Accuracy) -> Self {
+        self.accuracy = accuracy;
+        self
+    }
+
+    /// Render matches with `format`
+    #[must_use]
+    pub fn format(mut self, format: FormatOptions) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Replace or annotate matches
+    #[must_use]
+    pub fn mode(mut self, mode: StreamMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Text for the point in time `ht`, in its own tense
+    fn render(&self, ht: HumanTime) -> String {
+        ht.to_text(self.accuracy, ht.tense(self.accuracy), &self.format)
+    }
+
+    /// Text for the duration `ht` without tense
+    ///
+    /// Short durations are rendered precisely, as the rough "now" says nothing
+    /// about how long something took.
+    fn render_duration(&self, ht: HumanTime) -> String {
+        let accuracy = match ht.is_near_zero(self.accuracy) {
+            true => Accuracy::Precise,
+            false => self.accuracy,
+        };
+        ht.to_text(accuracy, Tense::Present, &self.format)
+    }
+}
+
+/// Copy `reader` to `writer`, humanizing timestamps and durations on the way
+///
+/// Recognized are RFC 3339 timestamps like "2024-05-01T12:00:00Z", unix epoch
+/// seconds and milliseconds like "1714564800" or "1714564800123", and compact
+/// durations like "12345ms" or "1h30m". Timestamps are rendered relative to the
+/// reference time of `options`, durations without tense. Lines that are not valid
+/// UTF-8 are copied unchanged.
+pub fn humanize_stream<R: BufRead, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    options: &StreamOptions,
+) -> io::Result<()> {
+    let mut line = vec![];
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        match std::str::from_utf8(&line) {
+            Ok(text) => writer.write_all(humanize_text(text, options).as_bytes())?,
+            Err(_) => writer.write_all(&line)?,
+        }
+        line.clear();
+    }
+
+    writer.flush()
+}
+
+/// Humanize all timestamps and durations in `text`, see `humanize_stream`
+pub fn humanize_text(text: &str, options: &StreamOptions) -> String {
+    let bytes = text.as_bytes();
+    let mut result = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let at_boundary = i == 0 || !is_word_byte(bytes[i - 1]);
+        if !at_boundary || !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        match humanize_at(&text[i..], options) {
+            Some((len, humanized)) => {
+                let original = &text[i..i + len];
+                result.push_str(&text[copied..i]);
+                match options.mode {
+                    StreamMode::Replace => result.push_str(&humanized),
+                    StreamMode::Annotate => {
+                        result.push_str(&format!("{} ({})", original, humanized))
+                    }
+                }
+                i += len;
+                copied = i;
+            }
+            None => {
+                while i < bytes.len() && is_word_byte(bytes[i]) {
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    result.push_str(&text[copied..]);
+    result
+}
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80
+}
+
+/// Length and humanized text of a timestamp or duration at the start of `text`
+fn humanize_at(text: &str, options: &StreamOptions) -> Option<(usize, String)> {
+    if let Some((len, time)) = parse_rfc3339(text) {
+        return Some((len, options.render(whole_seconds(time, options))));
+    }
+
+    // The word, without a trailing dot ending a sentence
+    let len = text
+        .char_indices()
+        .find(|&(_, c)| !(c.is_alphanumeric() || c == '.'))
+        .map_or(text.len(), |(i, _)| i);
+    let word = text[..len].trim_end_matches('.');
+
+    if word.bytes().all(|b| b.is_ascii_digit()) {
+        let epoch = match word.len() {
+            10 => Duration::from_secs(word.parse().ok()?),
+            13 => Duration::from_millis(word.parse().ok()?),
+            _ => return None,
+        };
+        let time = SystemTime::UNIX_EPOCH + epoch;
+        return Some((word.len(), options.render(whole_seconds(time, options))));
+    }
+
+    if word.ends_with(char::is_alphabetic) && !word.contains(char::is_whitespace) {
+        let ht = word.parse::<HumanTime>().ok()?;
+        return Some((word.len(), options.render_duration(ht)));
+    }
+
+    None
+}
+
+/// `HumanTime` from the reference time to `time` in whole seconds, like `From<SystemTime>`
+fn whole_seconds(time: SystemTime, options: &StreamOptions) -> HumanTime {
+    HumanTime::from(HumanTime::between(time, options.reference).as_secs())
+}
+
+/// Parse an RFC 3339 timestamp at the start of `text`, returning its length
+///
+/// The format is `YYYY-MM-DD(T| )HH:MM:SS[.fraction](Z|±HH:MM)`.
+fn parse_rfc3339(text: &str) -> Option<(usize, SystemTime)> {
+    let bytes = text.as_bytes();
+    let number = |from: usize, len: usize| -> Option<u32> {
+        let digits = bytes.get(from..from + len)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        text[from..from + len].parse().ok()
+    };
+    let byte = |at: usize| bytes.get(at).copied();
+
+    let year = number(0, 4)?;
+    let month = number(5, 2)?;
+    let day = number(8, 2)?;
+    let hour = number(11, 2)?;
+    let minute = number(14, 2)?;
+    let second = number(17, 2)?;
+    if byte(4)? != b'-' || byte(7)? != b'-' || byte(13)? != b':' || byte(16)? != b':' {
+        return None;
+    }
+    if !matches!(byte(10)?, b'T' | b't' | b' ') {
+        return None;
+    }
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    // `days_from_civil` rolls days past the end of the month, like Feb 30, into the next one
+    let days = days_from_civil(i64::from(year), month, day);
+    if civil_from_days(days) != (i64::from(year), month, day) {
+        return None;
+    }
+    // A leap second is taken as the first second of the next minute
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut len = 19;
+    let mut nanos = 0;
+    if byte(len) == Some(b'.') {
+        let digits = bytes[len + 1..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digits == 0 {
+            return None;
+        }
+        let fraction = &text[len + 1..len + 1 + digits.min(9)];
+        nanos = fraction.parse::<u32>().ok()? * 10u32.pow(9 - fraction.len() as u32);
+        len += 1 + digits;
+    }
+
+    let offset = match byte(len)? {
+        b'Z' | b'z' => {
+            len += 1;
+            0
+        }
+        sign @ (b'+' | b'-') => {
+            let hours = number(len + 1, 2)?;
+            let minutes = number(len + 4, 2)?;
+            if byte(len + 3)? != b':' {
+                return None;
+            }
+            len += 6;
+            let offset = i64::from(hours) * S_HOUR as i64 + i64::from(minutes) * S_MINUTE as i64;
+            if sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => return None,
+    };
+
+    let seconds = days * S_DAY as i64
+        + i64::from(hour) * S_HOUR as i64
+        + i64::from(minute) * S_MINUTE as i64
+        + i64::from(second)
+        - offset;
+
+    let time = match seconds {
+        s if s >= 0 => SystemTime::UNIX_EPOCH + Duration::new(s as u64, nanos),
+        s => {
+            SystemTime::UNIX_EPOCH - Duration::from_secs(s.unsigned_abs()) + Duration::new(0, nanos)
+        }
+    };
+
+    Some((len, time))
+}
@@ -0,0 +1,155 @@
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime};
+
+use futures_core::Stream;
+use tokio::time::{sleep, Instant, Sleep};
+
+use crate::format::{FormatOptions, Style};
+use crate::humantime::{Accuracy, HumanTime};
+
+/// Live relative label for a fixed point in time, e.g. "5 minutes ago"
+///
+/// The ticker is a `Stream` of labels, and each call to `tick` likewise waits
+/// until the text changes and yields the new text. Unit labels sleep exactly as
+/// long as `HumanTime::next_change_in_seconds` says, or until near-zero phrases
+/// switch from future to past. Decimals are checked every second and clocks on
+/// every change of their last digit. The stream never ends. The ticker runs no
+/// background task, so nothing is left running once it is dropped.
+///
+/// Time is measured with tokio's clock, so a paused runtime controls it as well.
+pub struct RelativeTimeTicker {
+    target: SystemTime,
+    reference: SystemTime,
+    started: Instant,
+    accuracy: Accuracy,
+    options: FormatOptions,
+    last: Option<String>,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl RelativeTimeTicker {
+    /// Ticker for `target`, measured from the current system time
+    pub fn new(target: SystemTime) -> Self {
+        Self::with_reference(target, SystemTime::now())
+    }
+
+    /// Ticker for `target`, measured from `reference` as the current time
+    pub fn with_reference(target: SystemTime, reference: SystemTime) -> Self {
+        Self {
+            target,
+            reference,
+            started: Instant::now(),
+            accuracy: Accuracy::Rough,
+            options: FormatOptions::default(),
+            last: None,
+            sleep: None,
+        }
+    }
+
+    /// Render the label with `accuracy`
+    #[must_use]
+    pub fn accuracy(mut self, accuracy: Accuracy) -> Self {
+        self.accuracy = accuracy;
+        self
+    }
+
+    /// Render the label with `options`
+    #[must_use]
+    pub fn options(mut self, options: FormatOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Current label, which may differ from the last one yielded by `tick`
+    pub fn current(&self) -> String {
+        let ht = HumanTime::between(self.target, self.now());
+        // Like `HumanTime::from(SystemTime)`, only whole seconds are shown, unless
+        // a clock shows fractions of a second
+        let ht = match self.options.style {
+            Style::Clock(clock) if clock.fraction > 0 => ht,
+            _ => HumanTime::from(ht.as_secs()),
+        };
+        ht.to_text(self.accuracy, ht.tense(self.accuracy), &self.options)
+    }
+
+    /// Wait until the label changes and return it, the first call returns immediately
+    pub async fn tick(&mut self) -> String {
+        poll_fn(|cx| self.poll_tick(cx)).await
+    }
+
+    /// Yield the label if it changed, otherwise sleep until the next change
+    fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<String> {
+        loop {
+            let text = self.current();
+            if self.last.as_ref() != Some(&text) {
+                self.last = Some(text.clone());
+                self.sleep = None;
+                return Poll::Ready(text);
+            }
+
+            let until = self.until_next_change();
+            let pending = self.sleep.get_or_insert_with(|| Box::pin(sleep(until)));
+            if pending.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            self.sleep = None;
+        }
+    }
+
+    /// The current time, advanced by tokio's clock since the ticker was created
+    fn now(&self) -> SystemTime {
+        self.reference + self.started.elapsed()
+    }
+
+    /// Time until the label changes, or a time before that
+    ///
+    /// Decimals are checked every second, which the labels are cut to, and
+    /// clocks with every change of their last digit.
+    fn until_next_change(&self) -> Duration {
+        let ht = HumanTime::between(self.target, self.now());
+        match self.options.style {
+            Style::Units if self.leaves_near_zero_future(ht) => {
+                // The future phrase turns into the past one once no whole second is left
+                let crossing = ht.duration() - Duration::from_secs(1) + Duration::from_nanos(1);
+                ht.next_change_in_seconds(self.accuracy).min(crossing)
+            }
+            Style::Units => ht.next_change_in_seconds(self.accuracy),
+            Style::Fractional { .. } => until_next_step(ht, Duration::from_secs(1)),
+            Style::Clock(clock) => {
+                let digits = u32::from(clock.fraction.min(9));
+                until_next_step(ht, Duration::from_nanos(10u64.pow(9 - digits)))
+            }
+        }
+    }
+
+    /// Whether `ht` shows the near-zero phrase for the future, with whole seconds left
+    fn leaves_near_zero_future(&self, ht: HumanTime) -> bool {
+        self.options.near_zero.is_some()
+            && ht.is_near_zero(self.accuracy)
+            && !ht.is_negative()
+            && ht.as_secs() > 0
+    }
+}
+
+/// Time until `ht`, cut to multiples of `step`, changes
+fn until_next_step(ht: HumanTime, step: Duration) -> Duration {
+    let step = step.as_nanos();
+    let remainder = ht.duration().as_nanos() % step;
+    let nanos = match ht.is_negative() || ht.is_zero() {
+        // Past durations grow to the next multiple
+        true => step - remainder,
+        // Future durations shrink below the current multiple
+        false => remainder + 1,
+    };
+    Duration::from_nanos(nanos as u64)
+}
+
+impl Stream for RelativeTimeTicker {
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        self.poll_tick(cx).map(Some)
+    }
+}
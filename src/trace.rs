@@ -0,0 +1,248 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::Duration;
+
+use tracing_core::field::{display, DisplayValue, Field, Visit};
+use tracing_subscriber::field::{MakeVisitor, VisitFmt, VisitOutput};
+use tracing_subscriber::fmt::format::{DefaultVisitor, Writer};
+
+use crate::format::FormatOptions;
+use crate::humantime::{Accuracy, HumanTime, Tense, Unit};
+
+/// Duration that is recorded in tracing events as precise text, e.g. "1 second and 234 ms"
+///
+/// `tracing::Value` cannot be implemented outside of tracing, so record it with
+/// the `%` or `?` sigil, or through `as_value`:
+///
+/// ```
+/// use std::time::Duration;
+/// use time_humanize::HumanDuration;
+///
+/// let elapsed = HumanDuration(Duration::from_millis(1234));
+/// tracing::info!(elapsed = %elapsed, "request done");
+/// tracing::info!(elapsed = elapsed.as_value(), "request done");
+///
+/// assert_eq!("1 second and 234 ms", elapsed.to_string());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HumanDuration(pub Duration);
+
+impl HumanDuration {
+    /// Value to record as a tracing field
+    pub fn as_value(&self) -> DisplayValue<&Self> {
+        display(self)
+    }
+}
+
+impl From<Duration> for HumanDuration {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = HumanTime::from(self.0).to_text_en(Accuracy::Precise, Tense::Present);
+        f.write_str(&text)
+    }
+}
+
+impl fmt::Debug for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Field suffixes recognized as durations by default
+const SUFFIXES: [(&str, Unit); 6] = [
+    ("_ns", Unit::Nanosecond),
+    ("_us", Unit::Microsecond),
+    ("_ms", Unit::Millisecond),
+    ("_s", Unit::Second),
+    ("_secs", Unit::Second),
+    ("_seconds", Unit::Second),
+];
+
+/// Field formatter for `tracing_subscriber::fmt` that humanizes duration fields
+///
+/// Numeric fields whose name ends in `_ns`, `_us`, `_ms`, `_s`, `_secs` or
+/// `_seconds`, and fields added with `field`, are rendered as precise text.
+/// Everything else is formatted like `DefaultFields` does. Only the fields of
+/// the console output change, a JSON layer keeps recording the raw numbers.
+///
+/// ```
+/// use time_humanize::HumanDurationFields;
+///
+/// let subscriber = tracing_subscriber::fmt()
+///     .fmt_fields(HumanDurationFields::new())
+///     .finish();
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     // elapsed_ms=1 second and 234 ms
+///     tracing::info!(elapsed_ms = 1234u64, "request done");
+/// });
+/// ```
+#[derive(Clone)]
+pub struct HumanDurationFields {
+    fields: Vec<(&'static str, Unit)>,
+    suffixes: bool,
+    accuracy: Accuracy,
+    options: FormatOptions,
+}
+
+impl HumanDurationFields {
+    /// Humanize fields with the default suffixes as precise text
+    pub fn new() -> Self {
+        Self {
+            fields: vec![],
+            suffixes: true,
+            accuracy: Accuracy::Precise,
+            options: FormatOptions::default(),
+        }
+    }
+
+    /// Also humanize the field `name`, holding a number of `unit`
+    #[must_use]
+    pub fn field(mut self, name: &'static str, unit: Unit) -> Self {
+        self.fields.push((name, unit));
+        self
+    }
+
+    /// Only humanize fields added with `field`, ignoring the default suffixes
+    #[must_use]
+    pub fn without_suffixes(mut self) -> Self {
+        self.suffixes = false;
+        self
+    }
+
+    /// Render durations with `accuracy`
+    #[must_use]
+    pub fn accuracy(mut self, accuracy: Accuracy) -> Self {
+        self.accuracy = accuracy;
+        self
+    }
+
+    /// Render durations with `options`
+    #[must_use]
+    pub fn options(mut self, options: FormatOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Unit of the field `name`, if it holds a duration
+    fn unit(&self, name: &str) -> Option<Unit> {
+        let field = self.fields.iter().find(|(field, _)| *field == name);
+        let suffix = SUFFIXES
+            .iter()
+            .filter(|_| self.suffixes)
+            .find(|(suffix, _)| name.len() > suffix.len() && name.ends_with(suffix));
+
+        field.or(suffix).map(|&(_, unit)| unit)
+    }
+}
+
+impl Default for HumanDurationFields {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> MakeVisitor<Writer<'a>> for HumanDurationFields {
+    type Visitor = HumanDurationVisitor<'a>;
+
+    fn make_visitor(&self, target: Writer<'a>) -> Self::Visitor {
+        HumanDurationVisitor {
+            fields: self.clone(),
+            inner: DefaultVisitor::new(target, true),
+        }
+    }
+}
+
+/// Visitor of `HumanDurationFields`
+pub struct HumanDurationVisitor<'a> {
+    fields: HumanDurationFields,
+    inner: DefaultVisitor<'a>,
+}
+
+impl HumanDurationVisitor<'_> {
+    /// Record the field as text if it is a duration field, `nanos` converting its units
+    fn record_duration(&mut self, field: &Field, nanos: impl FnOnce(u128) -> Option<u128>) -> bool {
+        let total = match self
+            .fields
+            .unit(field.name())
+            .and_then(|unit| nanos(unit.as_nanos()))
+        {
+            Some(total) => total,
+            None => return false,
+        };
+        let secs = match u64::try_from(total / 1_000_000_000) {
+            Ok(secs) => secs,
+            Err(_) => return false,
+        };
+
+        let duration = Duration::new(secs, (total % 1_000_000_000) as u32);
+        let text = HumanTime::from(duration).to_text(
+            self.fields.accuracy,
+            Tense::Present,
+            &self.fields.options,
+        );
+        self.inner.record_debug(field, &format_args!("{}", text));
+
+        true
+    }
+}
+
+impl Visit for HumanDurationVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        let nanos = |unit: u128| {
+            let nanos = value * unit as f64;
+            // Negative, NaN and too large values are recorded as they are
+            if nanos >= 0.0 && nanos < u128::MAX as f64 {
+                Some(nanos as u128)
+            } else {
+                None
+            }
+        };
+        if !self.record_duration(field, nanos) {
+            self.inner.record_f64(field, value);
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        let nanos = |unit: u128| u128::try_from(value).ok()?.checked_mul(unit);
+        if !self.record_duration(field, nanos) {
+            self.inner.record_i64(field, value);
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        let nanos = |unit: u128| u128::from(value).checked_mul(unit);
+        if !self.record_duration(field, nanos) {
+            self.inner.record_u64(field, value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.inner.record_str(field, value);
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+        self.inner.record_error(field, value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.inner.record_debug(field, value);
+    }
+}
+
+impl VisitOutput<fmt::Result> for HumanDurationVisitor<'_> {
+    fn finish(self) -> fmt::Result {
+        self.inner.finish()
+    }
+}
+
+impl VisitFmt for HumanDurationVisitor<'_> {
+    fn writer(&mut self) -> &mut dyn fmt::Write {
+        self.inner.writer()
+    }
+}
@@ -0,0 +1,592 @@
+use std::convert::TryInto;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::calendar::{civil_from_days, days_from_civil, CalendarPeriod};
+use crate::humantime::{unix_seconds, HumanTime, S_DAY, S_HOUR, S_MINUTE};
+use crate::locale::Locale;
+
+/// Directory the zones of `TimeZone::from_system` are read from, unless `TZDIR` is set
+const ZONEINFO: &str = "/usr/share/zoneinfo";
+
+/// Error returned when a time zone cannot be loaded
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TzError {
+    /// The zone could not be read
+    Io(io::Error),
+    /// The zone name is no relative path within the zoneinfo directory
+    InvalidName(String),
+    /// The data is no TZif file, with a description of what is wrong
+    InvalidData(&'static str),
+}
+
+impl fmt::Display for TzError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "cannot read time zone: {}", err),
+            Self::InvalidName(name) => write!(f, "invalid time zone name \"{}\"", name),
+            Self::InvalidData(what) => write!(f, "invalid time zone data: {}", what),
+        }
+    }
+}
+
+impl Error for TzError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for TzError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// A time zone of the tz database, with the history of its UTC offsets
+///
+/// Zones are read from TZif files as found in `/usr/share/zoneinfo`. Times after
+/// the last transition of the file follow the POSIX TZ rule in its footer, so
+/// daylight saving time keeps changing in the far future.
+///
+/// ```no_run
+/// use std::time::SystemTime;
+/// use time_humanize::{English, HumanTime, TimeZone};
+///
+/// let zone = TimeZone::from_system("Europe/Berlin")?;
+/// let ht = HumanTime::from_days(-1);
+/// println!("{}", ht.to_calendar_text_in(SystemTime::now(), &zone, &English));
+/// # Ok::<(), time_humanize::TzError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimeZone {
+    /// Transitions as unix seconds, with the local time type in effect from then on
+    transitions: Vec<(i64, usize)>,
+    /// UTC offsets of the local time types in seconds
+    offsets: Vec<i32>,
+    /// Rule for times after the last transition
+    rule: Option<PosixRule>,
+}
+
+impl TimeZone {
+    /// Coordinated Universal Time, without any offset
+    pub fn utc() -> Self {
+        Self {
+            transitions: vec![],
+            offsets: vec![0],
+            rule: None,
+        }
+    }
+
+    /// Zone described by the contents of a TZif file
+    pub fn from_tzif(data: &[u8]) -> Result<Self, TzError> {
+        let mut reader = Reader { data };
+        let mut header = reader.header()?;
+        let mut time_size = 4;
+        if header.version >= 2 {
+            // Skip the data of version 1, which repeats the data with 64-bit times
+            reader.take(header.data_len(time_size))?;
+            header = reader.header()?;
+            time_size = 8;
+        }
+
+        let times = (0..header.transitions)
+            .map(|_| reader.int(time_size))
+            .collect::<Result<Vec<_>, _>>()?;
+        let types = reader.take(header.types_of_transitions)?;
+        let offsets = (0..header.types)
+            .map(|_| {
+                let offset = reader.int(4)? as i32;
+                reader.take(2)?;
+                Ok(offset)
+            })
+            .collect::<Result<Vec<_>, TzError>>()?;
+        reader.take(header.data_len(time_size) - header.len_before_designations(time_size))?;
+
+        if offsets.is_empty() {
+            return Err(TzError::InvalidData("no local time types"));
+        }
+        if types
+            .iter()
+            .any(|&index| usize::from(index) >= offsets.len())
+        {
+            return Err(TzError::InvalidData(
+                "transition to unknown local time type",
+            ));
+        }
+
+        let rule = match header.version {
+            0 | 1 => None,
+            _ => reader.footer()?,
+        };
+
+        Ok(Self {
+            transitions: times
+                .into_iter()
+                .zip(types.iter().map(|&index| usize::from(index)))
+                .collect(),
+            offsets,
+            rule,
+        })
+    }
+
+    /// Zone `name` like "Europe/Berlin" from the zoneinfo directory of the system
+    ///
+    /// The directory is taken from the `TZDIR` environment variable and defaults
+    /// to `/usr/share/zoneinfo`.
+    pub fn from_system(name: &str) -> Result<Self, TzError> {
+        let path = Path::new(name);
+        let is_relative = path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if name.is_empty() || !is_relative {
+            return Err(TzError::InvalidName(name.into()));
+        }
+
+        let directory = env::var_os("TZDIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| ZONEINFO.into());
+        Self::from_tzif(&fs::read(directory.join(path))?)
+    }
+
+    /// Zone of the system, as set by the `TZ` environment variable or `/etc/localtime`
+    ///
+    /// `TZ` may name a zone of the zoneinfo directory, give the path of a TZif
+    /// file or hold a POSIX TZ rule like "CET-1CEST,M3.5.0,M10.5.0/3".
+    pub fn local() -> Result<Self, TzError> {
+        let tz = match env::var("TZ") {
+            Ok(tz) if !tz.is_empty() => tz,
+            _ => return Self::from_tzif(&fs::read("/etc/localtime")?),
+        };
+
+        let name = tz.strip_prefix(':').unwrap_or(&tz);
+        if Path::new(name).is_absolute() {
+            return Self::from_tzif(&fs::read(name)?);
+        }
+
+        Self::from_system(name).or_else(|err| match PosixRule::parse(name) {
+            Some(rule) => Ok(Self {
+                transitions: vec![],
+                offsets: vec![rule.std_offset],
+                rule: Some(rule),
+            }),
+            None => Err(err),
+        })
+    }
+
+    /// Offset of the zone at `time`, in seconds east of UTC
+    pub fn utc_offset(&self, time: SystemTime) -> i32 {
+        self.offset_at(unix_seconds(time))
+    }
+
+    fn offset_at(&self, unix: i64) -> i32 {
+        let after = self.transitions.partition_point(|&(at, _)| at <= unix);
+        match (after, &self.rule) {
+            (0, Some(rule)) if self.transitions.is_empty() => rule.offset_at(unix),
+            // Before the first transition the first local time type applies
+            (0, _) => self.offsets[0],
+            (n, Some(rule)) if n == self.transitions.len() => rule.offset_at(unix),
+            (n, _) => self.offsets[self.transitions[n - 1].1],
+        }
+    }
+}
+
+/// Humanization anchored in a time zone
+impl HumanTime {
+    /// Wall-clock time from `reference` to `target` in `zone`
+    ///
+    /// Across a change to or from daylight saving time the wall clock moves by
+    /// more or less than the time that passed, so from noon before the clocks
+    /// spring forward to noon after is a whole day, even though 23 hours passed.
+    pub fn between_in(target: SystemTime, reference: SystemTime, zone: &TimeZone) -> HumanTime {
+        let shift = i128::from(zone.utc_offset(target)) - i128::from(zone.utc_offset(reference));
+        let elapsed = HumanTime::between(target, reference);
+        let nanos = match elapsed.is_negative() {
+            true => -(elapsed.duration().as_nanos() as i128),
+            false => elapsed.duration().as_nanos() as i128,
+        } + shift * 1_000_000_000;
+
+        let duration = Duration::new(
+            (nanos.unsigned_abs() / 1_000_000_000) as u64,
+            (nanos.unsigned_abs() % 1_000_000_000) as u32,
+        );
+        match nanos < 0 {
+            true => -HumanTime::from(duration),
+            false => HumanTime::from(duration),
+        }
+    }
+
+    /// Calendar position of this `HumanTime`, taken as an offset from `reference`,
+    /// on the wall clock of `zone`
+    pub fn calendar_period_in(self, reference: SystemTime, zone: &TimeZone) -> CalendarPeriod {
+        let reference = unix_seconds(reference);
+        let target = reference + self.as_secs();
+        CalendarPeriod::between(
+            target,
+            reference,
+            zone.offset_at(target),
+            zone.offset_at(reference),
+        )
+    }
+
+    /// Gives calendar-relative text like "yesterday" on the wall clock of `zone`
+    #[must_use]
+    pub fn to_calendar_text_in(
+        self,
+        reference: SystemTime,
+        zone: &TimeZone,
+        locale: &dyn Locale,
+    ) -> String {
+        locale.calendar(self.calendar_period_in(reference, zone))
+    }
+}
+
+/// Counts of a TZif header
+struct Header {
+    version: u8,
+    ut_indicators: usize,
+    std_indicators: usize,
+    leap_seconds: usize,
+    transitions: usize,
+    types_of_transitions: usize,
+    types: usize,
+    designation_bytes: usize,
+}
+
+impl Header {
+    /// Length of the data following the header, with times of `time_size` bytes
+    fn data_len(&self, time_size: usize) -> usize {
+        self.len_before_designations(time_size)
+            + self.designation_bytes
+            + self.leap_seconds * (time_size + 4)
+            + self.std_indicators
+            + self.ut_indicators
+    }
+
+    /// Length of the transitions and local time types
+    fn len_before_designations(&self, time_size: usize) -> usize {
+        self.transitions * time_size + self.types_of_transitions + self.types * 6
+    }
+}
+
+/// Cursor over the bytes of a TZif file
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], TzError> {
+        if self.data.len() < len {
+            return Err(TzError::InvalidData("unexpected end of data"));
+        }
+
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    /// Big-endian signed integer of `size` bytes
+    fn int(&mut self, size: usize) -> Result<i64, TzError> {
+        let bytes = self.take(size)?;
+        let value = bytes
+            .iter()
+            .fold(0u64, |value, &b| value << 8 | u64::from(b));
+        Ok(match size {
+            4 => i64::from(value as u32 as i32),
+            _ => value as i64,
+        })
+    }
+
+    fn header(&mut self) -> Result<Header, TzError> {
+        if self.take(4)? != b"TZif" {
+            return Err(TzError::InvalidData("missing TZif magic"));
+        }
+        let version = match self.take(1)?[0] {
+            0 => 1,
+            version @ b'2'..=b'9' => version - b'0',
+            _ => return Err(TzError::InvalidData("unknown version")),
+        };
+        self.take(15)?;
+
+        let mut count = || self.int(4).map(|count| count as u32 as usize);
+        let (ut_indicators, std_indicators, leap_seconds) = (count()?, count()?, count()?);
+        let (transitions, types, designation_bytes) = (count()?, count()?, count()?);
+
+        Ok(Header {
+            version,
+            ut_indicators,
+            std_indicators,
+            leap_seconds,
+            transitions,
+            types_of_transitions: transitions,
+            types,
+            designation_bytes,
+        })
+    }
+
+    /// The POSIX TZ rule between two newlines after the data of version 2 and later
+    fn footer(&mut self) -> Result<Option<PosixRule>, TzError> {
+        let footer = match self.data {
+            [b'\n', rest @ ..] => rest,
+            _ => return Err(TzError::InvalidData("missing footer")),
+        };
+        let end = footer
+            .iter()
+            .position(|&b| b == b'\n')
+            .ok_or(TzError::InvalidData("unterminated footer"))?;
+
+        match &footer[..end] {
+            [] => Ok(None),
+            rule => std::str::from_utf8(rule)
+                .ok()
+                .and_then(PosixRule::parse)
+                .map(Some)
+                .ok_or(TzError::InvalidData("invalid TZ rule in footer")),
+        }
+    }
+}
+
+/// A POSIX TZ rule like "CET-1CEST,M3.5.0,M10.5.0/3"
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PosixRule {
+    /// Standard offset in seconds east of UTC
+    std_offset: i32,
+    dst: Option<DstRule>,
+}
+
+/// When daylight saving time starts and ends each year
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct DstRule {
+    /// Daylight saving offset in seconds east of UTC
+    offset: i32,
+    /// Day and local standard time of the start
+    start: (RuleDay, i64),
+    /// Day and local daylight saving time of the end
+    end: (RuleDay, i64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RuleDay {
+    /// "Jn", day 1 to 365 of the year, never counting February 29
+    Julian(u16),
+    /// "n", day 0 to 365 of the year, counting February 29
+    ZeroBased(u16),
+    /// "Mm.w.d", weekday `d` from Sunday of week `w` of month `m`, where week 5 is the last one
+    MonthWeekday { month: u32, week: u32, weekday: u32 },
+}
+
+impl RuleDay {
+    /// Days since 1970-01-01 of this day in `year`
+    fn day_in(self, year: i64) -> i64 {
+        let first_of_year = days_from_civil(year, 1, 1);
+        let is_leap = days_from_civil(year + 1, 1, 1) - first_of_year == 366;
+        match self {
+            Self::Julian(day) => {
+                first_of_year + i64::from(day) - 1 + i64::from(is_leap && day >= 60)
+            }
+            Self::ZeroBased(day) => first_of_year + i64::from(day),
+            Self::MonthWeekday {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = days_from_civil(year, month, 1);
+                let next = match month {
+                    12 => days_from_civil(year + 1, 1, 1),
+                    _ => days_from_civil(year, month + 1, 1),
+                };
+                // 1970-01-01 was a Thursday, the fourth day from Sunday
+                let first_weekday = (first + 4).rem_euclid(7);
+                let day = first
+                    + (i64::from(weekday) - first_weekday).rem_euclid(7)
+                    + 7 * (i64::from(week) - 1);
+                if day >= next {
+                    day - 7
+                } else {
+                    day
+                }
+            }
+        }
+    }
+}
+
+impl PosixRule {
+    fn parse(rule: &str) -> Option<Self> {
+        let mut parser = RuleParser {
+            rest: rule.as_bytes(),
+        };
+
+        parser.name()?;
+        let std_offset = parser.time()?.checked_neg()?;
+        if parser.rest.is_empty() {
+            return Some(Self {
+                std_offset,
+                dst: None,
+            });
+        }
+
+        parser.name()?;
+        let offset = match parser.rest.first() {
+            None | Some(b',') => std_offset.checked_add(S_HOUR as i32)?,
+            Some(_) => parser.time()?.checked_neg()?,
+        };
+        let (start, end) = match parser.eat(b',') {
+            true => {
+                let start = parser.transition()?;
+                parser.eat(b',').then_some(())?;
+                (start, parser.transition()?)
+            }
+            // The rules of the United States
+            false => (
+                (
+                    RuleDay::MonthWeekday {
+                        month: 3,
+                        week: 2,
+                        weekday: 0,
+                    },
+                    2 * S_HOUR as i64,
+                ),
+                (
+                    RuleDay::MonthWeekday {
+                        month: 11,
+                        week: 1,
+                        weekday: 0,
+                    },
+                    2 * S_HOUR as i64,
+                ),
+            ),
+        };
+
+        parser.rest.is_empty().then_some(Self {
+            std_offset,
+            dst: Some(DstRule { offset, start, end }),
+        })
+    }
+
+    fn offset_at(&self, unix: i64) -> i32 {
+        let dst = match &self.dst {
+            Some(dst) => dst,
+            None => return self.std_offset,
+        };
+
+        let day = S_DAY as i64;
+        let (year, _, _) = civil_from_days((unix + i64::from(self.std_offset)).div_euclid(day));
+        let start = dst.start.0.day_in(year) * day + dst.start.1 - i64::from(self.std_offset);
+        let end = dst.end.0.day_in(year) * day + dst.end.1 - i64::from(dst.offset);
+
+        // Daylight saving time spans the turn of the year on the southern hemisphere
+        let is_dst = match start <= end {
+            true => start <= unix && unix < end,
+            false => !(end <= unix && unix < start),
+        };
+        match is_dst {
+            true => dst.offset,
+            false => self.std_offset,
+        }
+    }
+}
+
+/// Cursor over the text of a POSIX TZ rule
+struct RuleParser<'a> {
+    rest: &'a [u8],
+}
+
+impl RuleParser<'_> {
+    fn eat(&mut self, byte: u8) -> bool {
+        match self.rest {
+            [first, rest @ ..] if *first == byte => {
+                self.rest = rest;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// A zone abbreviation, either alphabetic like "CET" or quoted like "<+03>"
+    fn name(&mut self) -> Option<()> {
+        let len = match self.eat(b'<') {
+            true => self.rest.iter().position(|&b| b == b'>')? + 1,
+            false => self
+                .rest
+                .iter()
+                .take_while(|b| b.is_ascii_alphabetic())
+                .count(),
+        };
+        if len < 3 {
+            return None;
+        }
+
+        self.rest = &self.rest[len..];
+        Some(())
+    }
+
+    fn number(&mut self) -> Option<i64> {
+        let len = self.rest.iter().take_while(|b| b.is_ascii_digit()).count();
+        let number = std::str::from_utf8(&self.rest[..len]).ok()?.parse().ok()?;
+        self.rest = &self.rest[len..];
+        Some(number)
+    }
+
+    /// A signed time like "-1", "5:30" or "+167", in seconds, `None` beyond the range of `i32`
+    fn time(&mut self) -> Option<i32> {
+        let sign = match self.eat(b'-') {
+            true => -1,
+            false => {
+                self.eat(b'+');
+                1
+            }
+        };
+
+        let mut seconds = self.number()?.checked_mul(S_HOUR as i64)?;
+        if self.eat(b':') {
+            seconds = seconds.checked_add(self.number()?.checked_mul(S_MINUTE as i64)?)?;
+            if self.eat(b':') {
+                seconds = seconds.checked_add(self.number()?)?;
+            }
+        }
+
+        (sign * seconds).try_into().ok()
+    }
+
+    /// A day with an optional local time like "M3.5.0/3", at 2:00 by default
+    fn transition(&mut self) -> Option<(RuleDay, i64)> {
+        let day = if self.eat(b'J') {
+            RuleDay::Julian(
+                self.number()?
+                    .try_into()
+                    .ok()
+                    .filter(|d| (1..=365).contains(d))?,
+            )
+        } else if self.eat(b'M') {
+            let month = self.number()?;
+            self.eat(b'.').then_some(())?;
+            let week = self.number()?;
+            self.eat(b'.').then_some(())?;
+            let weekday = self.number()?;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+                return None;
+            }
+            RuleDay::MonthWeekday {
+                month: month as u32,
+                week: week as u32,
+                weekday: weekday as u32,
+            }
+        } else {
+            RuleDay::ZeroBased(self.number()?.try_into().ok().filter(|d| *d <= 365)?)
+        };
+
+        let time = match self.eat(b'/') {
+            true => i64::from(self.time()?),
+            false => 2 * S_HOUR as i64,
+        };
+        Some((day, time))
+    }
+}
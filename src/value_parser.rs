@@ -0,0 +1,119 @@
+use std::ffi::OsStr;
+use std::fmt;
+use std::time::Duration;
+
+use clap::builder::{OsStr as ClapOsStr, TypedValueParser};
+use clap::error::{Error, ErrorKind};
+use clap::{Arg, Command};
+
+use crate::humantime::{Accuracy, HumanTime, Tense};
+
+/// clap value parser for `HumanTime` arguments
+///
+/// Accepts compact literals like "1h30m" or "01:30:00" as well as phrases like
+/// "in 2 days" or "1 hour and 30 minutes ago".
+///
+/// ```
+/// use clap::{Arg, Command};
+/// use time_humanize::{HumanTime, HumanTimeValueParser};
+///
+/// let matches = Command::new("app")
+///     .arg(Arg::new("delay").long("delay").value_parser(HumanTimeValueParser))
+///     .get_matches_from(["app", "--delay", "in 2 days"]);
+///
+/// assert_eq!(Some(&HumanTime::from_days(2)), matches.get_one::<HumanTime>("delay"));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HumanTimeValueParser;
+
+impl TypedValueParser for HumanTimeValueParser {
+    type Value = HumanTime;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let text = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        text.parse()
+            .map_err(|err| invalid_value(cmd, arg, text, err))
+    }
+}
+
+/// clap value parser for `std::time::Duration` arguments like `--timeout 1h30m`
+///
+/// Accepts the same text as `HumanTimeValueParser`, except for points in the past.
+/// Defaults can be given as `HumanTime`, which shows up in the help text in its
+/// precise form:
+///
+/// ```
+/// use clap::{Arg, Command};
+/// use std::time::Duration;
+/// use time_humanize::{DurationValueParser, HumanTime};
+///
+/// let mut cmd = Command::new("app").arg(
+///     Arg::new("timeout")
+///         .long("timeout")
+///         .value_parser(DurationValueParser)
+///         .default_value(HumanTime::from_minutes(90)),
+/// );
+///
+/// assert!(cmd.render_help().to_string().contains("[default: \"1 hour and 30 minutes\"]"));
+///
+/// let matches = cmd.get_matches_from(["app"]);
+/// assert_eq!(Some(&Duration::from_secs(5400)), matches.get_one::<Duration>("timeout"));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DurationValueParser;
+
+impl TypedValueParser for DurationValueParser {
+    type Value = Duration;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let ht = HumanTimeValueParser.parse_ref(cmd, arg, value)?;
+        if ht.is_negative() {
+            let text = value.to_string_lossy();
+            let err = format!(
+                "\"{}\" lies in the past, expected a duration like \"{}\"",
+                text,
+                (-ht).to_text_en(Accuracy::Precise, Tense::Present)
+            );
+            return Err(invalid_value(cmd, arg, &text, err));
+        }
+
+        Ok(ht.duration())
+    }
+}
+
+/// Error for an unparsable `value`, with a tip on the accepted formats
+fn invalid_value(cmd: &Command, arg: Option<&Arg>, value: &str, err: impl fmt::Display) -> Error {
+    let arg = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+    let message = format!(
+        "invalid value '{}' for '{}': {}\n\n  \
+         tip: use a duration like \"1h30m\", \"90s\", \"01:30:00\" or \"2 days\"\n",
+        value, arg, err
+    );
+
+    Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+}
+
+impl From<HumanTime> for ClapOsStr {
+    /// Precise text of `ht`, which the value parsers read back, e.g. for `Arg::default_value`
+    fn from(ht: HumanTime) -> Self {
+        let tense = if ht.is_negative() {
+            Tense::Past
+        } else {
+            Tense::Present
+        };
+
+        ClapOsStr::from(ht.to_text_en(Accuracy::Precise, tense))
+    }
+}
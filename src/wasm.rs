@@ -0,0 +1,233 @@
+use std::time::Duration;
+
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use crate::format::{Clock, FormatOptions, ListFormat, NearZero, NumberStyle, Style};
+use crate::humantime::{Accuracy, HumanTime, Tense, Unit};
+
+#[wasm_bindgen(typescript_custom_section)]
+const HUMANIZE_OPTIONS: &str = r#"
+/** Options of `humanize`, mirroring `FormatOptions` of the Rust crate */
+export interface HumanizeOptions {
+    /** Precise text like "1 hour and 30 minutes" instead of rough text like "2 hours" */
+    precise?: boolean;
+    /** Tense of the text, by default "past" for negative and "future" for positive durations */
+    tense?: "past" | "present" | "future";
+    /** Counts as "digits", as "words", or as words up to the given number */
+    numbers?: "digits" | "words" | number;
+    /** A list of units, a single unit with a fraction, or a digital clock */
+    style?: "units" | "fractional" | "clock";
+    /** Unit of the fractional style like "hours", by default the largest that fits */
+    unit?: string;
+    /** Decimals of the fractional style, 1 by default */
+    decimals?: number;
+    /** Fields of the clock style, see `Clock` */
+    clock?: { days?: boolean; hours?: boolean; pad?: boolean; fraction?: number; signed?: boolean };
+    /** Join units with this separator instead of ", " and " and " */
+    separator?: string;
+    /** Keep the comma before "and", "1 hour, 2 minutes, and 3 seconds" */
+    serialComma?: boolean;
+    /** Phrases for durations of up to ten seconds instead of "now", true for "just now" and "in a moment" */
+    nearZero?: boolean | { past: string; future: string };
+    /** Word before all unit names, like "business" */
+    qualifier?: string;
+}
+"#;
+
+/// Text for `seconds` from now, negative for the past, with JS `options` like `{ precise: true }`
+///
+/// Exported to JavaScript as `humanize(seconds, options?)`.
+#[wasm_bindgen(js_name = humanize)]
+pub fn wasm_humanize(seconds: f64, options: Option<Object>) -> Result<String, JsError> {
+    if !seconds.is_finite() {
+        return Err(JsError::new("seconds must be a finite number"));
+    }
+    let magnitude = Duration::try_from_secs_f64(seconds.abs())
+        .map_err(|_| JsError::new("seconds are out of range"))?;
+    let ht = match seconds < 0.0 {
+        true => -HumanTime::from(magnitude),
+        false => HumanTime::from(magnitude),
+    };
+
+    let options = match options {
+        Some(options) => Options::from_js(&options)?,
+        None => Options::default(),
+    };
+    let tense = options.tense.unwrap_or_else(|| ht.tense(options.accuracy));
+
+    Ok(ht.to_text(options.accuracy, tense, &options.format))
+}
+
+/// Seconds of text like "in 2 hours", "5 minutes ago" or "1h30m", negative for the past
+///
+/// Exported to JavaScript as `parse(text)`, which throws for text that is no duration.
+#[wasm_bindgen(js_name = parse)]
+pub fn wasm_parse(text: &str) -> Result<f64, JsError> {
+    let ht: HumanTime = text
+        .parse()
+        .map_err(|err| JsError::new(&format!("{}", err)))?;
+    let seconds = ht.duration().as_secs_f64();
+
+    match ht.is_negative() {
+        true => Ok(-seconds),
+        false => Ok(seconds),
+    }
+}
+
+/// Settings read from a JS options object
+struct Options {
+    accuracy: Accuracy,
+    tense: Option<Tense>,
+    format: FormatOptions,
+}
+
+impl Default for Options {
+    /// Rough English text in the tense of the duration
+    fn default() -> Self {
+        Self {
+            accuracy: Accuracy::Rough,
+            tense: None,
+            format: FormatOptions::new(),
+        }
+    }
+}
+
+impl Options {
+    fn from_js(object: &Object) -> Result<Self, JsError> {
+        let mut options = Self::default();
+        if boolean(object, "precise")?.unwrap_or(false) {
+            options.accuracy = Accuracy::Precise;
+        }
+
+        options.tense = match string(object, "tense")?.as_deref() {
+            None => None,
+            Some("past") => Some(Tense::Past),
+            Some("present") => Some(Tense::Present),
+            Some("future") => Some(Tense::Future),
+            Some(_) => return Err(invalid("tense", "\"past\", \"present\" or \"future\"")),
+        };
+
+        let mut format = FormatOptions::new();
+        if let Some(numbers) = get(object, "numbers")? {
+            let style = match (numbers.as_string().as_deref(), numbers.as_f64()) {
+                (Some("digits"), _) => NumberStyle::Digits,
+                (Some("words"), _) => NumberStyle::Words,
+                (_, Some(max)) if max >= 0.0 => NumberStyle::WordsUpTo(max as u64),
+                _ => return Err(invalid("numbers", "\"digits\", \"words\" or a number")),
+            };
+            format = format.numbers(style);
+        }
+
+        let style = match string(object, "style")?.as_deref() {
+            None | Some("units") => Style::Units,
+            Some("fractional") => Style::Fractional {
+                unit: match string(object, "unit")? {
+                    Some(unit) => Some(
+                        unit.parse::<Unit>()
+                            .map_err(|_| invalid("unit", "a unit like \"hours\""))?,
+                    ),
+                    None => None,
+                },
+                decimals: number(object, "decimals")?.map_or(1, |decimals| decimals as usize),
+            },
+            Some("clock") => Style::Clock(clock(object)?),
+            Some(_) => return Err(invalid("style", "\"units\", \"fractional\" or \"clock\"")),
+        };
+        format = format.style(style);
+
+        let separator = string(object, "separator")?;
+        let serial_comma = boolean(object, "serialComma")?;
+        if separator.is_some() || serial_comma.is_some() {
+            let mut list = separator.map_or_else(ListFormat::default, ListFormat::separated);
+            list.serial_comma = serial_comma.unwrap_or(false);
+            format = format.list(list);
+        }
+
+        if let Some(near_zero) = get(object, "nearZero")? {
+            let phrases = match near_zero.as_bool() {
+                Some(true) => Some(NearZero::default()),
+                Some(false) => None,
+                None => {
+                    let phrases: Object = near_zero
+                        .dyn_into()
+                        .map_err(|_| invalid("nearZero", "a boolean or { past, future }"))?;
+                    let phrase = |key| {
+                        string(&phrases, key)?
+                            .ok_or_else(|| invalid("nearZero", "a boolean or { past, future }"))
+                    };
+                    Some(NearZero::new(phrase("past")?, phrase("future")?))
+                }
+            };
+            if let Some(phrases) = phrases {
+                format = format.near_zero(phrases);
+            }
+        }
+
+        if let Some(qualifier) = string(object, "qualifier")? {
+            format = format.qualifier(qualifier);
+        }
+
+        options.format = format;
+        Ok(options)
+    }
+}
+
+/// Fields of the "clock" option, the defaults of `Clock` for missing ones
+fn clock(object: &Object) -> Result<Clock, JsError> {
+    let mut clock = Clock::default();
+    let fields: Object = match get(object, "clock")? {
+        Some(fields) => fields
+            .dyn_into()
+            .map_err(|_| invalid("clock", "an object"))?,
+        None => return Ok(clock),
+    };
+
+    clock.days = boolean(&fields, "days")?.unwrap_or(clock.days);
+    clock.hours = boolean(&fields, "hours")?.unwrap_or(clock.hours);
+    clock.pad = boolean(&fields, "pad")?.unwrap_or(clock.pad);
+    clock.signed = boolean(&fields, "signed")?.unwrap_or(clock.signed);
+    if let Some(fraction) = number(&fields, "fraction")? {
+        clock.fraction = fraction.clamp(0.0, 9.0) as u8;
+    }
+
+    Ok(clock)
+}
+
+/// Property `key` of `object`, `None` if it is missing, undefined or null
+fn get(object: &Object, key: &str) -> Result<Option<JsValue>, JsError> {
+    let value = Reflect::get(object, &JsValue::from_str(key))
+        .map_err(|_| JsError::new(&format!("cannot read option \"{}\"", key)))?;
+
+    match value.is_undefined() || value.is_null() {
+        true => Ok(None),
+        false => Ok(Some(value)),
+    }
+}
+
+fn boolean(object: &Object, key: &str) -> Result<Option<bool>, JsError> {
+    get(object, key)?
+        .map(|value| value.as_bool().ok_or_else(|| invalid(key, "a boolean")))
+        .transpose()
+}
+
+fn string(object: &Object, key: &str) -> Result<Option<String>, JsError> {
+    get(object, key)?
+        .map(|value| value.as_string().ok_or_else(|| invalid(key, "a string")))
+        .transpose()
+}
+
+fn number(object: &Object, key: &str) -> Result<Option<f64>, JsError> {
+    get(object, key)?
+        .map(|value| {
+            value
+                .as_f64()
+                .filter(|number| *number >= 0.0)
+                .ok_or_else(|| invalid(key, "a number of at least 0"))
+        })
+        .transpose()
+}
+
+fn invalid(key: &str, expected: &str) -> JsError {
+    JsError::new(&format!("option \"{}\" must be {}", key, expected))
+}
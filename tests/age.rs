@@ -0,0 +1,135 @@
+macro_rules! age_test {
+    ($($name:ident: $secs:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            assert_eq!($text, HumanTime::from_seconds($secs).age().to_string());
+        })+
+    }
+}
+
+#[cfg(test)]
+mod age {
+    use std::time::{Duration, SystemTime};
+    use time_humanize::{Age, AgeFormat, FormatOptions, HumanTime, Locale, NumberStyle};
+
+    const H: i64 = 3600;
+    const D: i64 = 24 * H;
+
+    // test_name: age in seconds, "Age text"
+    age_test! {
+        zero: 0, "0 seconds old",
+        seconds: 30, "30 seconds old",
+        minute: 90, "1 minute old",
+        hours: 5 * H, "5 hours old",
+        day: D, "1 day old",
+        days: 13 * D, "13 days old",
+        two_weeks: 14 * D, "2 weeks old",
+        six_weeks: 45 * D, "6 weeks old",
+        three_months: 90 * D, "3 months old",
+        eleven_months: 359 * D, "11 months old",
+        twelve_months: 364 * D, "12 months old",
+        eighteen_months: 550 * D, "18 months old",
+        two_years: 730 * D, "2 years old",
+        three_years: 3 * 365 * D + 100 * D, "3 years old",
+        negative: -2 * D, "2 days old",
+    }
+
+    fn date(year: i64, month: u32, day: u32) -> SystemTime {
+        // Days from 1970-01-01, good enough for the dates below
+        let days = |y: i64, m: u32, d: u32| {
+            let y = if m <= 2 { y - 1 } else { y };
+            let era = y.div_euclid(400);
+            let yoe = y.rem_euclid(400);
+            let mp = i64::from((m + 9) % 12);
+            let doy = (153 * mp + 2) / 5 + i64::from(d) - 1;
+            era * 146_097 + yoe * 365 + yoe / 4 - yoe / 100 + doy - 719_468
+        };
+        SystemTime::UNIX_EPOCH + Duration::from_secs(days(year, month, day) as u64 * 86400)
+    }
+
+    #[test]
+    fn birthday() {
+        let born = date(2020, 3, 15);
+        assert_eq!(
+            "2 years old",
+            Age::between(born, date(2023, 3, 14), 0).to_string()
+        );
+        assert_eq!(
+            "3 years old",
+            Age::between(born, date(2023, 3, 15), 0).to_string()
+        );
+        assert_eq!(2, Age::between(born, date(2023, 3, 14), 0).years());
+        assert_eq!(36, Age::between(born, date(2023, 3, 15), 0).months());
+    }
+
+    #[test]
+    fn calendar_months() {
+        let born = date(2023, 1, 31);
+        assert_eq!(0, Age::between(born, date(2023, 2, 28), 0).months());
+        assert_eq!(1, Age::between(born, date(2023, 3, 1), 0).months());
+        assert_eq!(
+            "4 weeks old",
+            Age::between(born, date(2023, 3, 1), 0).to_string()
+        );
+        assert_eq!(
+            "3 months old",
+            Age::between(born, date(2023, 5, 1), 0).to_string()
+        );
+    }
+
+    #[test]
+    fn utc_offset() {
+        // Born on the evening of March 14 in UTC, already March 15 in UTC+2
+        let born = date(2020, 3, 14) + Duration::from_secs(23 * 3600);
+        let now = date(2021, 3, 15) + Duration::from_secs(12 * 3600);
+        assert_eq!(12, Age::between(born, now, 0).months());
+        assert_eq!(12, Age::between(born, now, 2 * 3600).months());
+
+        let now = date(2021, 3, 14) + Duration::from_secs(22 * 3600);
+        assert_eq!(11, Age::between(born, now, 0).months());
+        assert_eq!(11, Age::between(born, now, 2 * 3600).months());
+    }
+
+    #[test]
+    fn not_born_yet() {
+        let now = date(2023, 1, 1);
+        assert_eq!(Age::default(), Age::between(date(2024, 1, 1), now, 0));
+    }
+
+    #[test]
+    fn format() {
+        let format = AgeFormat {
+            weeks_from_days: 7,
+            months_from_months: 6,
+            years_from_months: 12,
+        };
+        let options = FormatOptions::default();
+        let text = |days: i64| HumanTime::from_days(days).age().to_text(&format, &options);
+
+        assert_eq!("6 days old", text(6));
+        assert_eq!("1 week old", text(7));
+        assert_eq!("25 weeks old", text(179));
+        assert_eq!("6 months old", text(180));
+        assert_eq!("1 year old", text(365));
+    }
+
+    struct German;
+
+    impl Locale for German {
+        fn age(&self, age: &str) -> String {
+            format!("{} alt", age)
+        }
+    }
+
+    #[test]
+    fn locale() {
+        let options = FormatOptions::new()
+            .locale(&German)
+            .numbers(NumberStyle::Words);
+        let age = HumanTime::from_days(3 * 365).age();
+        assert_eq!(
+            "three years alt",
+            age.to_text(&AgeFormat::default(), &options)
+        );
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod batch {
+    use std::time::Duration;
+    use time_humanize::{humanize_batch, BatchOptions, FormatOptions, HumanTime, Locale, Unit};
+
+    fn micros(values: &[i64]) -> Vec<HumanTime> {
+        values
+            .iter()
+            .map(|&us| {
+                let ht = HumanTime::from(Duration::from_micros(us.unsigned_abs()));
+                if us < 0 {
+                    -ht
+                } else {
+                    ht
+                }
+            })
+            .collect()
+    }
+
+    macro_rules! batch_tests {
+        ($($name:ident: $values:expr => $expected:expr,)+) => {
+            $(
+                #[test]
+                fn $name() {
+                    let texts = humanize_batch(&micros(&$values), &BatchOptions::new());
+                    assert_eq!($expected.to_vec(), texts);
+                }
+            )+
+        };
+    }
+
+    batch_tests! {
+        below_and_above_a_second: [950_000, 1_030_000] => ["0.95s", "1.03s"],
+        tens_of_units: [1_500_000, 12_500_000] => ["1.5s", "12.5s"],
+        hundreds_of_units: [5_000, 42_000, 640_000] => ["5ms", "42ms", "640ms"],
+        whole_units: [1_000_000, 2_000_000] => ["1s", "2s"],
+        trailing_zero_dropped: [1_500_000, 2_000_000] => ["1.5s", "2.0s"],
+        small_values_round: [1_000, 2_500_000] => ["0.0s", "2.5s"],
+        microseconds: [3, 250] => ["3µs", "250µs"],
+        minutes: [90_000_000, 150_000_000] => ["1.5m", "2.5m"],
+        negative: [-950_000, 1_030_000] => ["-0.95s", "1.03s"],
+        negative_rounding_to_zero: [-1, 2_010_000] => ["0.00s", "2.01s"],
+        zeros: [0, 0] => ["0s", "0s"],
+    }
+
+    #[test]
+    fn empty() {
+        assert!(humanize_batch(&[], &BatchOptions::new()).is_empty());
+    }
+
+    #[test]
+    fn pad() {
+        let options = BatchOptions::new().pad(true);
+        let texts = humanize_batch(&micros(&[-950_000, 1_030_000, 10_000]), &options);
+        assert_eq!(vec!["-0.95s", " 1.03s", " 0.01s"], texts);
+    }
+
+    #[test]
+    fn pad_counts_columns() {
+        let options = BatchOptions::new().pad(true);
+        let texts = humanize_batch(&micros(&[5, 42, 640]), &options);
+        assert_eq!(vec!["  5µs", " 42µs", "640µs"], texts);
+        // "µ" takes two bytes but a single column
+        assert_eq!(
+            vec![6, 6, 6],
+            texts.iter().map(String::len).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn fixed_unit_and_decimals() {
+        let options = BatchOptions::new().unit(Unit::Millisecond).decimals(1);
+        let texts = humanize_batch(&micros(&[950_000, 1_030_000]), &options);
+        assert_eq!(vec!["950.0ms", "1030.0ms"], texts);
+    }
+
+    struct Wide;
+
+    impl Locale for Wide {
+        fn unit_symbol(&self, unit: Unit) -> String {
+            match unit {
+                Unit::Second => "秒".into(),
+                _ => "?".into(),
+            }
+        }
+
+        fn decimal_separator(&self) -> &str {
+            ","
+        }
+    }
+
+    #[test]
+    fn locale() {
+        let options = BatchOptions::new()
+            .pad(true)
+            .format(FormatOptions::new().locale(&Wide));
+        let texts = humanize_batch(&micros(&[1_500_000, 12_000_000]), &options);
+        assert_eq!(vec![" 1,5秒", "12,0秒"], texts);
+    }
+}
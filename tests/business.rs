@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod business {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    use time_humanize::{Accuracy, BusinessCalendar, FormatOptions, HumanTime, Tense, Weekday};
+
+    /// Monday, 2024-04-29 00:00 UTC
+    const MONDAY: u64 = 1_714_348_800;
+
+    /// `hour` o'clock on the given day after Monday, 2024-04-29, in UTC
+    fn at(day: u64, hour: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(MONDAY + day * 86400 + hour * 3600)
+    }
+
+    fn hours(hours: u64) -> Duration {
+        Duration::from_secs(hours * 3600)
+    }
+
+    fn business(ht: HumanTime, accuracy: Accuracy) -> String {
+        let options = FormatOptions::new().qualifier("business");
+        ht.to_text(accuracy, Tense::Present, &options)
+    }
+
+    #[test]
+    fn working_time() {
+        let calendar = BusinessCalendar::new();
+        assert_eq!(hours(7), calendar.working_time(at(0, 10), at(0, 20)));
+        assert_eq!(hours(0), calendar.working_time(at(0, 18), at(1, 8)));
+        // Friday 16:00 to Monday 10:00 skips the weekend
+        assert_eq!(hours(2), calendar.working_time(at(4, 16), at(7, 10)));
+        assert_eq!(hours(0), calendar.working_time(at(1, 10), at(0, 10)));
+    }
+
+    #[test]
+    fn between() {
+        let calendar = BusinessCalendar::new();
+        let ht = calendar.between(at(0, 10), at(3, 10));
+        assert!(ht.is_negative());
+        assert_eq!("3 business days", business(ht, Accuracy::Rough));
+
+        let ht = calendar.between(at(7, 12), at(0, 9));
+        assert!(!ht.is_negative());
+        assert_eq!(
+            "1 business week and 3 business hours",
+            business(ht, Accuracy::Precise)
+        );
+    }
+
+    #[test]
+    fn within_a_day() {
+        let calendar = BusinessCalendar::new();
+        let ht = calendar.between(at(4, 16), at(7, 11));
+        assert_eq!("3 business hours", business(ht, Accuracy::Rough));
+    }
+
+    #[test]
+    fn rendered_with_options() {
+        let calendar = BusinessCalendar::new();
+        let options = FormatOptions::new().qualifier("business");
+        let ht = calendar.between(at(0, 9), at(2, 13));
+        assert_eq!(
+            "2 business days and 4 business hours ago",
+            format!("{:#}", ht.with_options(options.clone()))
+        );
+        let ht = calendar.between(at(0, 9), at(1, 10));
+        assert_eq!("1 business day ago", ht.with_options(options).to_string());
+    }
+
+    #[test]
+    fn working_hours_and_days() {
+        let calendar = BusinessCalendar::new()
+            .working_days(&[Weekday::Saturday, Weekday::Sunday])
+            .working_hours((10, 30), (14, 0));
+        assert_eq!(
+            Duration::from_secs(7 * 3600),
+            calendar.working_time(at(0, 0), at(7, 0))
+        );
+        let ht = calendar.between(at(5, 0), at(7, 0));
+        assert_eq!("1 business week", business(ht, Accuracy::Precise));
+    }
+
+    #[test]
+    fn utc_offset() {
+        // 9:00 to 17:00 in UTC+2 is 7:00 to 15:00 UTC
+        let calendar = BusinessCalendar::new().utc_offset(2 * 3600);
+        assert_eq!(hours(2), calendar.working_time(at(0, 6), at(0, 9)));
+        assert_eq!(hours(1), calendar.working_time(at(0, 14), at(0, 17)));
+    }
+
+    #[test]
+    fn holidays() {
+        let calendar = BusinessCalendar::new().holiday(2024, 5, 1);
+        assert_eq!(hours(16), calendar.working_time(at(1, 9), at(3, 17)));
+    }
+
+    #[test]
+    fn holiday_list() {
+        let list = "\n# May\n2024-05-01 Labour Day\n  2024-05-02\n";
+        let calendar = BusinessCalendar::new().holidays(list).unwrap();
+        assert_eq!(hours(8), calendar.working_time(at(1, 9), at(3, 17)));
+    }
+
+    #[test]
+    fn holiday_ical() {
+        let ical = "BEGIN:VCALENDAR\r\n\
+                    VERSION:2.0\r\n\
+                    BEGIN:VEVENT\r\n\
+                    SUMMARY:Labour Day\r\n\
+                    DTSTART;VALUE=DATE:20240501\r\n\
+                    DTEND;VALUE=DATE:20240503\r\n\
+                    END:VEVENT\r\n\
+                    BEGIN:VEVENT\r\n\
+                    DTSTART:20240506T000000Z\r\n\
+                    DTEND:20240506T235959Z\r\n\
+                    END:VEVENT\r\n\
+                    END:VCALENDAR\r\n";
+        let calendar = BusinessCalendar::new().holidays(ical).unwrap();
+        let expected = BusinessCalendar::new()
+            .holiday(2024, 5, 1)
+            .holiday(2024, 5, 2)
+            .holiday(2024, 5, 6);
+        assert_eq!(expected, calendar);
+    }
+
+    #[test]
+    fn invalid_holidays() {
+        let err = BusinessCalendar::new()
+            .holidays("2024-05-01\n2024-02-30 Nope\n")
+            .unwrap_err();
+        assert_eq!(2, err.line());
+        assert_eq!(
+            "invalid holiday \"2024-02-30 Nope\" on line 2",
+            err.to_string()
+        );
+
+        let err = BusinessCalendar::new()
+            .holidays("BEGIN:VEVENT\nDTSTART:2024\nEND:VEVENT\n")
+            .unwrap_err();
+        assert_eq!(2, err.line());
+    }
+
+    #[test]
+    #[should_panic(expected = "working hours")]
+    fn working_hours_reversed() {
+        let _ = BusinessCalendar::new().working_hours((17, 0), (9, 0));
+    }
+}
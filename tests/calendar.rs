@@ -0,0 +1,50 @@
+macro_rules! calendar_test {
+    ($($name:ident: $offset:expr, $utc_offset:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            // Wednesday, 2024-05-15 12:00:00 UTC
+            let reference = SystemTime::UNIX_EPOCH + Duration::from_secs(1_715_774_400);
+            let ht = HumanTime::from_seconds($offset);
+            assert_eq!($text, ht.to_calendar_text(reference, $utc_offset, &English));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod calendar {
+    use std::time::{Duration, SystemTime};
+    use time_humanize::{CalendarPeriod, English, HumanTime, Weekday};
+
+    const H: i64 = 60 * 60;
+    const D: i64 = 24 * H;
+
+    // test_name: offset from reference in seconds, UTC offset in seconds, "Calendar text"
+    calendar_test! {
+        now: 0, 0, "today at 12:00",
+        minus_11h: -11 * H - 59 * 60, 0, "today at 00:01",
+        minus_12h: -12 * H - 60, 0, "yesterday",
+        plus_12h: 12 * H, 0, "tomorrow",
+        plus_11h_east: 11 * H, 7200, "tomorrow",
+        minus_12h_east: -12 * H, 7200, "today at 02:00",
+        minus_2d: -2 * D, 0, "last Monday",
+        plus_3d: 3 * D, 0, "next Saturday",
+        minus_7d: -7 * D, 0, "last week",
+        plus_8d: 8 * D, 0, "next week",
+        minus_20d: -20 * D, 0, "last month",
+        plus_25d: 25 * D, 0, "next month",
+        minus_200d: -200 * D, 0, "last year",
+        plus_250d: 250 * D, 0, "next year",
+        minus_3y: -3 * 365 * D, 0, "3 years ago",
+        minus_60d: -60 * D, 0, "2 months ago",
+    }
+
+    #[test]
+    fn period() {
+        let reference = SystemTime::UNIX_EPOCH + Duration::from_secs(1_715_774_400);
+        let ht = HumanTime::from_days(-5);
+        assert_eq!(
+            CalendarPeriod::LastWeekday(Weekday::Friday),
+            ht.calendar_period(reference, 0)
+        );
+    }
+}
@@ -0,0 +1,64 @@
+#![cfg(all(feature = "capi", unix))]
+
+#[cfg(test)]
+mod capi {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    /// Builds the static library with the `capi` feature in `target_dir`
+    ///
+    /// The crate is no static library by default, so a separate build is needed.
+    fn static_library(target_dir: &Path) -> PathBuf {
+        let status = Command::new(env!("CARGO"))
+            .args(["rustc", "--lib", "--features", "capi", "--crate-type"])
+            .args(["staticlib", "--target-dir"])
+            .arg(target_dir)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .status()
+            .expect("cannot run cargo");
+        assert!(status.success(), "cannot build the static library");
+
+        target_dir.join("debug/libtime_humanize.a")
+    }
+
+    #[test]
+    fn header_is_fresh() {
+        let generated = fs::read_to_string(Path::new(env!("OUT_DIR")).join("time_humanize.h"));
+        let checked_in = fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("include/time_humanize.h"),
+        );
+        assert_eq!(
+            generated.unwrap(),
+            checked_in.unwrap(),
+            "include/time_humanize.h is stale, regenerate it with cbindgen"
+        );
+    }
+
+    #[test]
+    fn c_program() {
+        let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let target_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("capi");
+        let library = static_library(&target_dir);
+        let program = target_dir.join("capi_test");
+
+        let status = Command::new(env::var("CC").unwrap_or_else(|_| "cc".to_owned()))
+            .args(["-std=c99", "-Wall", "-Werror", "-I"])
+            .arg(manifest.join("include"))
+            .arg(manifest.join("tests/capi/test.c"))
+            .arg(library)
+            .args(["-lpthread", "-ldl", "-lm", "-o"])
+            .arg(&program)
+            .status()
+            .expect("cannot run the C compiler");
+        assert!(status.success(), "cannot compile tests/capi/test.c");
+
+        let output = Command::new(&program).output().unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
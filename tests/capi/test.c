@@ -0,0 +1,69 @@
+/* Exercises the C API of the capi feature, run by tests/capi.rs */
+#include <stdio.h>
+#include <string.h>
+
+#include "time_humanize.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                                   \
+    do {                                                                   \
+        if (!(condition)) {                                                \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,         \
+                    __LINE__, #condition);                                 \
+            failures++;                                                    \
+        }                                                                  \
+    } while (0)
+
+static void check_format(int64_t secs, uint32_t nanos, int flags, const char *expected) {
+    char buf[64];
+    int len = time_humanize_format(secs, nanos, flags, buf, sizeof buf);
+    if (len != (int)strlen(expected) || strcmp(buf, expected) != 0) {
+        fprintf(stderr, "format(%lld, %u, %d): expected \"%s\", got \"%s\" (%d)\n",
+                (long long)secs, nanos, flags, expected, buf, len);
+        failures++;
+    }
+}
+
+static void check_parse(const char *text, int64_t secs, uint32_t nanos) {
+    int64_t parsed_secs = 0;
+    uint32_t parsed_nanos = 0;
+    int result = time_humanize_parse(text, &parsed_secs, &parsed_nanos);
+    if (result != 0 || parsed_secs != secs || parsed_nanos != nanos) {
+        fprintf(stderr, "parse(\"%s\"): expected %lld.%09u, got %lld.%09u (%d)\n", text,
+                (long long)secs, nanos, (long long)parsed_secs, parsed_nanos, result);
+        failures++;
+    }
+}
+
+int main(void) {
+    check_format(5400, 0, 0, "in an hour");
+    check_format(5400, 0, TIME_HUMANIZE_PRECISE, "in 1 hour and 30 minutes");
+    check_format(-90, 0, TIME_HUMANIZE_PRECISE, "1 minute and 30 seconds ago");
+    check_format(-91, 500000000, TIME_HUMANIZE_PRECISE, "1 minute, 30 seconds and 500 ms ago");
+    check_format(5400, 0, TIME_HUMANIZE_PRECISE | TIME_HUMANIZE_PRESENT, "1 hour and 30 minutes");
+    check_format(180, 0, TIME_HUMANIZE_WORDS, "in three minutes");
+
+    /* Like snprintf, the length of the full text even if it is cut */
+    char small[6];
+    CHECK(time_humanize_format(5400, 0, 0, small, sizeof small) == 10);
+    CHECK(strcmp(small, "in an") == 0);
+    CHECK(time_humanize_format(5400, 0, 0, NULL, 0) == 10);
+
+    CHECK(time_humanize_format(0, 1000000000, 0, small, sizeof small) == -1);
+    CHECK(time_humanize_format(0, 0, 64, small, sizeof small) == -1);
+    CHECK(time_humanize_format(0, 0, 0, NULL, 8) == -1);
+
+    check_parse("in 2 days", 172800, 0);
+    check_parse("5 minutes ago", -300, 0);
+    check_parse("1h30m", 5400, 0);
+    check_parse("1.5 seconds ago", -2, 500000000);
+
+    int64_t secs = 7;
+    uint32_t nanos = 7;
+    CHECK(time_humanize_parse("whenever", &secs, &nanos) == -1);
+    CHECK(secs == 7 && nanos == 7);
+    CHECK(time_humanize_parse(NULL, &secs, &nanos) == -1);
+
+    return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod cli {
+    use std::io::Write;
+    use std::process::{Command, Output, Stdio};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn humanize(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_humanize"))
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    fn stdout(args: &[&str]) -> String {
+        let output = humanize(args);
+        assert!(output.status.success(), "{:?}", output);
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[test]
+    fn seconds() {
+        assert_eq!("in an hour\n", stdout(&["3600"]));
+        assert_eq!("2 minutes ago\n", stdout(&["-95"]));
+    }
+
+    #[test]
+    fn precise_past() {
+        assert_eq!(
+            "1 minute and 35 seconds ago\n",
+            stdout(&["--precise", "--past", "95"])
+        );
+    }
+
+    #[test]
+    fn since() {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let timestamp = (now.as_secs() - 2 * 60 * 60).to_string();
+        assert_eq!("2 hours ago\n", stdout(&["--since", &timestamp]));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("172800\n", stdout(&["--parse", "in 2 days"]));
+        assert_eq!("-5400\n", stdout(&["--parse", "1h30m ago"]));
+        assert_eq!("0.5\n", stdout(&["--parse", "500ms"]));
+    }
+
+    #[test]
+    fn parse_error() {
+        let output = humanize(&["--parse", "5 fortnights"]);
+        assert!(!output.status.success());
+        assert_eq!(
+            "humanize: unknown unit \"fortnights\"\n",
+            String::from_utf8(output.stderr).unwrap()
+        );
+    }
+
+    #[test]
+    fn options() {
+        assert_eq!("-01:02:03\n", stdout(&["--clock", "-3723"]));
+        assert_eq!("in 1.5 hours\n", stdout(&["--fractional", "5400"]));
+        assert_eq!(
+            "in one hour, two minutes, and five seconds\n",
+            stdout(&["--precise", "--words", "--serial-comma", "3725"])
+        );
+        assert_eq!("just now\n", stdout(&["--near-zero", "-3"]));
+    }
+
+    #[test]
+    fn unknown_option() {
+        let output = humanize(&["--bogus"]);
+        assert_eq!(Some(2), output.status.code());
+    }
+
+    #[test]
+    fn filter() {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_humanize"))
+            .args(["--filter", "--precise"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(b"took 12345ms, then  (95s)\tok 42\n")
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+
+        assert_eq!(
+            "took 12 seconds and 345 ms, then  (1 minute and 35 seconds)\tok 42\n",
+            String::from_utf8(output.stdout).unwrap()
+        );
+    }
+}
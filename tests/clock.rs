@@ -0,0 +1,55 @@
+macro_rules! clock_test {
+    ($($name:ident: $ht:expr, $clock:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let options = FormatOptions::new().style(Style::Clock($clock));
+            assert_eq!($text, $ht.to_text(Accuracy::Precise, Tense::Past, &options));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod clock {
+    use std::time::Duration;
+    use time_humanize::{Accuracy, Clock, FormatOptions, HumanTime, Style, Tense};
+
+    const DEFAULT: Clock = Clock {
+        days: false,
+        hours: true,
+        pad: true,
+        fraction: 0,
+        signed: true,
+    };
+
+    const MINUTES: Clock = Clock {
+        hours: false,
+        ..DEFAULT
+    };
+
+    const DAYS_MILLIS: Clock = Clock {
+        days: true,
+        fraction: 3,
+        ..DEFAULT
+    };
+
+    // test_name: HumanTime expression, Clock options, "Clock text"
+    clock_test! {
+        zero: HumanTime::now(), DEFAULT, "00:00:00",
+        hms: HumanTime::from_seconds(3723), DEFAULT, "01:02:03",
+        many_hours: HumanTime::from_hours(76), DEFAULT, "76:00:00",
+        unpadded: HumanTime::from_seconds(3723), Clock { pad: false, ..DEFAULT }, "1:02:03",
+        minutes: HumanTime::from_seconds(65), MINUTES, "01:05",
+        minutes_unpadded: HumanTime::from_seconds(65), Clock { pad: false, ..MINUTES }, "1:05",
+        minutes_with_hours: HumanTime::from_seconds(3723), MINUTES, "01:02:03",
+        negative: HumanTime::from_seconds(-5), MINUTES, "-00:05",
+        unsigned: HumanTime::from_seconds(-5), Clock { signed: false, ..MINUTES }, "00:05",
+        days: HumanTime::from(Duration::new(3 * 86400 + 4 * 3600 + 5 * 60 + 6, 789_000_000)), DAYS_MILLIS, "3d 04:05:06.789",
+        less_than_a_day: HumanTime::from(Duration::new(4 * 3600, 5_000_000)), DAYS_MILLIS, "04:00:00.005",
+        nanos: HumanTime::from(Duration::new(1, 123_456_789)), Clock { fraction: 9, ..MINUTES }, "00:01.123456789",
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(DEFAULT, Clock::default());
+    }
+}
@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod eta {
+    use std::time::{Duration, Instant};
+
+    use time_humanize::{Eta, HumanTime};
+
+    /// Clock for the samples, starting at an arbitrary instant
+    struct Clock(Instant);
+
+    impl Clock {
+        fn at(&self, secs: u64) -> Instant {
+            self.0 + Duration::from_secs(secs)
+        }
+    }
+
+    fn clock() -> Clock {
+        Clock(Instant::now())
+    }
+
+    #[test]
+    fn unknown_without_progress() {
+        let clock = clock();
+        let mut eta = Eta::new();
+        assert_eq!(None, eta.remaining());
+        assert_eq!("time remaining unknown", eta.to_string());
+
+        eta.sample(clock.at(0), 0, 100);
+        assert_eq!(None, eta.remaining());
+
+        eta.sample(clock.at(5), 0, 100);
+        assert_eq!(Some(0.0), eta.rate());
+        assert_eq!(None, eta.remaining());
+    }
+
+    #[test]
+    fn constant_rate() {
+        let clock = clock();
+        let mut eta = Eta::new();
+        for step in 0..=10 {
+            eta.sample(clock.at(step * 10), step * 20, 1000);
+        }
+
+        assert_eq!(Some(2.0), eta.rate());
+        assert_eq!(Some(HumanTime::from_seconds(400)), eta.remaining());
+        assert_eq!("about 6 minutes remaining", eta.to_string());
+    }
+
+    #[test]
+    fn smoothing() {
+        let clock = clock();
+        let mut eta = Eta::new().smoothing(0.5);
+        eta.sample(clock.at(0), 0, 100);
+        eta.sample(clock.at(1), 10, 100);
+        eta.sample(clock.at(2), 12, 100);
+
+        // Halfway between 10 and 2 units per second
+        assert_eq!(Some(6.0), eta.rate());
+        assert_eq!(14, eta.remaining().unwrap().as_secs());
+        assert_eq!("less than a minute remaining", eta.to_string());
+    }
+
+    #[test]
+    fn without_smoothing() {
+        let clock = clock();
+        let mut eta = Eta::new().smoothing(1.0);
+        eta.sample(clock.at(0), 0, 10_000);
+        eta.sample(clock.at(1), 100, 10_000);
+        eta.sample(clock.at(2), 101, 10_000);
+
+        assert_eq!(Some(1.0), eta.rate());
+        assert_eq!("about 2 hours remaining", eta.to_string());
+    }
+
+    #[test]
+    fn ignored_samples() {
+        let clock = clock();
+        let mut eta = Eta::new();
+        eta.sample(clock.at(10), 0, 100);
+        eta.sample(clock.at(20), 10, 100);
+
+        // Same or earlier timestamps do not change the rate
+        eta.sample(clock.at(20), 50, 100);
+        eta.sample(clock.at(5), 60, 100);
+        assert_eq!(Some(1.0), eta.rate());
+    }
+
+    #[test]
+    fn restart() {
+        let clock = clock();
+        let mut eta = Eta::new();
+        eta.sample(clock.at(0), 0, 100);
+        eta.sample(clock.at(10), 50, 100);
+        eta.sample(clock.at(20), 10, 100);
+        assert_eq!(None, eta.rate());
+
+        eta.sample(clock.at(30), 20, 100);
+        assert_eq!(Some(1.0), eta.rate());
+        assert_eq!(Some(HumanTime::from_seconds(80)), eta.remaining());
+        assert_eq!("about a minute remaining", eta.to_string());
+    }
+
+    #[test]
+    fn done() {
+        let clock = clock();
+        let mut eta = Eta::new();
+        eta.sample(clock.at(0), 100, 100);
+        assert_eq!(Some(HumanTime::now()), eta.remaining());
+        assert_eq!("done", eta.to_string());
+    }
+
+    #[test]
+    fn stalled() {
+        let clock = clock();
+        let mut eta = Eta::new();
+        eta.sample(clock.at(0), 0, u64::MAX);
+        eta.sample(clock.at(1_000_000), 1, u64::MAX);
+        assert_eq!(Some(Duration::MAX), eta.remaining().map(|r| r.duration()));
+        assert_eq!("about 584942417355 years remaining", eta.to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "smoothing must be in (0, 1]")]
+    fn invalid_smoothing() {
+        let _ = Eta::new().smoothing(0.0);
+    }
+}
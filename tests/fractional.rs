@@ -0,0 +1,111 @@
+macro_rules! fractional_test {
+    ($($name:ident: $duration:expr, $unit:expr, $decimals:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let ht = HumanTime::from($duration);
+            let options = FormatOptions::new().style(Style::Fractional {
+                unit: $unit,
+                decimals: $decimals,
+            });
+            assert_eq!($text, ht.to_text(Accuracy::Precise, Tense::Present, &options));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod fractional {
+    use std::time::Duration;
+    use time_humanize::{Accuracy, FormatOptions, HumanTime, Locale, Style, Tense, Unit};
+
+    // test_name: Duration expression, Option<Unit>, decimals, "Fractional text"
+    fractional_test! {
+        zero: Duration::from_secs(0), None, 1, "0.0 seconds",
+        one_hour: Duration::from_secs(3600), None, 1, "1.0 hour",
+        one_hour_no_decimals: Duration::from_secs(3600), None, 0, "1 hour",
+        ninety_minutes: Duration::from_secs(5400), None, 1, "1.5 hours",
+        two_days: Duration::from_secs(2 * 86400 + 6 * 3600), None, 2, "2.25 days",
+        almost_one: Duration::from_secs(3599), Some(Unit::Hour), 2, "1.00 hour",
+        minutes_of_hour: Duration::from_secs(5400), Some(Unit::Minute), 0, "90 minutes",
+        half_second: Duration::from_millis(500), None, 1, "500.0 ms",
+        seconds_unit: Duration::from_millis(500), Some(Unit::Second), 1, "0.5 seconds",
+        ten_days: Duration::from_secs(10 * 86400), None, 1, "1.4 weeks",
+    }
+
+    #[test]
+    fn tense() {
+        let ht = HumanTime::from_seconds(-5400);
+        let options = FormatOptions::new().style(Style::Fractional {
+            unit: None,
+            decimals: 1,
+        });
+        assert_eq!(
+            "1.5 hours ago",
+            ht.to_text(Accuracy::Rough, Tense::Past, &options)
+        );
+    }
+
+    struct German;
+
+    impl Locale for German {
+        fn unit_name(&self, unit: Unit, plural: bool) -> String {
+            match (unit, plural) {
+                (Unit::Second, false) => "Sekunde",
+                (Unit::Second, true) => "Sekunden",
+                (Unit::Minute, false) => "Minute",
+                (Unit::Minute, true) => "Minuten",
+                (Unit::Hour, false) => "Stunde",
+                (Unit::Hour, true) => "Stunden",
+                _ => "Zeit",
+            }
+            .into()
+        }
+
+        fn single_unit(&self, unit: Unit) -> String {
+            format!("eine {}", self.unit_name(unit, false))
+        }
+
+        fn now(&self) -> String {
+            "jetzt".into()
+        }
+
+        fn with_tense(&self, text: &str, tense: Tense) -> String {
+            match tense {
+                Tense::Past => format!("vor {}", text),
+                Tense::Future => format!("in {}", text),
+                Tense::Present => text.into(),
+            }
+        }
+
+        fn decimal_separator(&self) -> &str {
+            ","
+        }
+    }
+
+    #[test]
+    fn decimal_separator() {
+        let ht = HumanTime::from_seconds(5400);
+        let options = FormatOptions::new()
+            .locale(&German)
+            .style(Style::Fractional {
+                unit: None,
+                decimals: 2,
+            });
+        assert_eq!(
+            "1,50 Stunden",
+            ht.to_text(Accuracy::Rough, Tense::Present, &options)
+        );
+    }
+
+    #[test]
+    fn locale_in_every_accuracy() {
+        let options = FormatOptions::new().locale(&German);
+        let rough = |seconds| HumanTime::from_seconds(seconds).with_options(options.clone());
+
+        assert_eq!("vor eine Stunde", rough(-3600).to_string());
+        assert_eq!("in 2 Stunden", rough(7200).to_string());
+        assert_eq!("vor 30 Sekunden", rough(-30).to_string());
+        assert_eq!("jetzt", rough(5).to_string());
+        assert_eq!("vor 1 Stunde", format!("{:#}", rough(-3600)));
+        assert_eq!("in 0 Sekunden", format!("{:#}", rough(0)));
+    }
+}
@@ -0,0 +1,75 @@
+macro_rules! iso8601_test {
+    ($($name:ident: $duration:expr, $iso:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            assert_eq!($iso, HumanTime::from($duration).to_iso8601());
+        })+
+    }
+}
+
+#[cfg(test)]
+mod html {
+    use std::time::{Duration, SystemTime};
+    use time_humanize::{Accuracy, FormatOptions, HumanTime, NearZero};
+
+    /// Saturday, 2024-05-04 12:00 UTC
+    fn reference() -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_714_824_000)
+    }
+
+    // test_name: Duration expression, "ISO 8601 duration"
+    iso8601_test! {
+        zero: Duration::from_secs(0), "PT0S",
+        seconds: Duration::from_secs(42), "PT42S",
+        minutes: Duration::from_secs(5400), "PT1H30M",
+        days: Duration::from_secs(2 * 86400), "P2D",
+        weeks_as_days: Duration::from_secs(21 * 86400 + 3661), "P21DT1H1M1S",
+        fraction: Duration::from_millis(1500), "PT1.5S",
+        only_fraction: Duration::from_micros(250), "PT0.00025S",
+        negative: HumanTime::from_seconds(-90).duration(), "PT1M30S",
+    }
+
+    #[test]
+    fn future() {
+        assert_eq!(
+            r#"<time datetime="2024-05-06T14:30:00Z" title="May 6, 2024 14:30 UTC">in 2 days</time>"#,
+            HumanTime::from_seconds(2 * 86400 + 9000).to_html(reference())
+        );
+    }
+
+    #[test]
+    fn before_epoch() {
+        let reference = SystemTime::UNIX_EPOCH;
+        assert_eq!(
+            r#"<time datetime="1969-12-31T23:00:00Z" title="December 31, 1969 23:00 UTC">an hour ago</time>"#,
+            HumanTime::from_hours(-1).to_html(reference)
+        );
+    }
+
+    #[test]
+    fn with_options() {
+        let options = FormatOptions::new().qualifier("<b>");
+        assert_eq!(
+            r#"<time datetime="2024-05-04T10:30:00Z" title="May 4, 2024 10:30 UTC">1 &lt;b&gt; hour and 30 &lt;b&gt; minutes ago</time>"#,
+            HumanTime::from_seconds(-5400).to_html_with(reference(), Accuracy::Precise, &options)
+        );
+    }
+
+    #[test]
+    fn escaping() {
+        let options = FormatOptions::new().near_zero(NearZero::new("\"just\" now & then", "soon"));
+        assert_eq!(
+            r#"<time datetime="PT3S">&quot;just&quot; now &amp; then</time>"#,
+            HumanTime::from_seconds(-3).to_duration_html(Accuracy::Rough, &options)
+        );
+    }
+
+    #[test]
+    fn duration() {
+        let options = FormatOptions::new();
+        assert_eq!(
+            r#"<time datetime="P1DT2H">a day</time>"#,
+            HumanTime::from_hours(-26).to_duration_html(Accuracy::Rough, &options)
+        );
+    }
+}
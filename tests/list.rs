@@ -0,0 +1,62 @@
+macro_rules! list_test {
+    ($($name:ident: $duration:expr, $list:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let ht = HumanTime::from($duration);
+            let options = FormatOptions::new().list($list);
+            assert_eq!($text, ht.to_text(Accuracy::Precise, Tense::Present, &options));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod list {
+    use time_humanize::{Accuracy, FormatOptions, HumanTime, ListFormat, Locale, Tense};
+
+    fn oxford() -> ListFormat {
+        ListFormat {
+            serial_comma: true,
+            ..ListFormat::default()
+        }
+    }
+
+    // test_name: seconds, ListFormat, "Precise text"
+    list_test! {
+        default_one: 3600, ListFormat::default(), "1 hour",
+        default_two: 3602, ListFormat::default(), "1 hour and 2 seconds",
+        default_three: 3723, ListFormat::default(), "1 hour, 2 minutes and 3 seconds",
+        oxford_two: 3602, oxford(), "1 hour and 2 seconds",
+        oxford_three: 3723, oxford(), "1 hour, 2 minutes, and 3 seconds",
+        space: 3723, ListFormat::separated(" "), "1 hour 2 minutes 3 seconds",
+        dot_two: 3602, ListFormat::separated(" · "), "1 hour · 2 seconds",
+        dot_three: 3723, ListFormat::separated(" · "), "1 hour · 2 minutes · 3 seconds",
+    }
+
+    struct Spanish;
+
+    impl Locale for Spanish {
+        fn list_format(&self) -> ListFormat {
+            ListFormat {
+                conjunction: " y ".into(),
+                pair: " y ".into(),
+                ..ListFormat::default()
+            }
+        }
+    }
+
+    #[test]
+    fn locale() {
+        let ht = HumanTime::from(3723);
+        let options = FormatOptions::new().locale(&Spanish);
+        assert_eq!(
+            "1 hour, 2 minutes y 3 seconds",
+            ht.to_text(Accuracy::Precise, Tense::Present, &options)
+        );
+    }
+
+    #[test]
+    fn join() {
+        assert_eq!("", ListFormat::default().join::<&str>(&[]));
+        assert_eq!("a, b, c, and d", oxford().join(&["a", "b", "c", "d"]));
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod near_zero {
+    use time_humanize::{Accuracy, FormatOptions, HumanTime, NearZero, Tense};
+
+    fn options() -> FormatOptions {
+        FormatOptions::new().near_zero(NearZero::default())
+    }
+
+    #[test]
+    fn minus_5s() {
+        let ht = HumanTime::from_seconds(-5);
+        assert_eq!("just now", format!("{}", ht.with_options(options())));
+    }
+
+    #[test]
+    fn plus_5s() {
+        let ht = HumanTime::from_seconds(5);
+        assert_eq!("in a moment", format!("{}", ht.with_options(options())));
+    }
+
+    #[test]
+    fn zero() {
+        let ht = HumanTime::now();
+        assert_eq!("just now", format!("{}", ht.with_options(options())));
+    }
+
+    #[test]
+    fn plus_15s() {
+        let ht = HumanTime::from_seconds(15);
+        assert_eq!("in 15 seconds", format!("{}", ht.with_options(options())));
+    }
+
+    #[test]
+    fn without_phrases() {
+        let ht = HumanTime::from_seconds(-5);
+        assert_eq!("now", format!("{}", ht.with_options(FormatOptions::new())));
+        assert_eq!("now", ht.to_text_en(Accuracy::Rough, Tense::Past));
+    }
+
+    #[test]
+    fn custom_phrases() {
+        let ht = HumanTime::from_seconds(-5);
+        let options = FormatOptions::new().near_zero(NearZero::new("gerade eben", "gleich"));
+        assert_eq!("gerade eben", format!("{}", ht.with_options(options)));
+    }
+
+    #[test]
+    fn pinned_past() {
+        let ht = HumanTime::from_hours(1);
+        let display = ht.with_options(options()).tense(Tense::Past);
+        assert_eq!("an hour ago", format!("{}", display));
+    }
+
+    #[test]
+    fn pinned_past_near_zero() {
+        let ht = HumanTime::from_seconds(5);
+        let display = ht.with_options(options()).tense(Tense::Past);
+        assert_eq!("just now", format!("{}", display));
+    }
+
+    #[test]
+    fn pinned_present() {
+        let ht = HumanTime::from_seconds(-95);
+        let display = ht.with_options(FormatOptions::new()).tense(Tense::Present);
+        assert_eq!("1 minute and 35 seconds", format!("{:#}", display));
+    }
+
+    #[test]
+    fn width_is_precise() {
+        // Like `HumanTime`, a width picks the precise text that fits
+        let ht = HumanTime::from_seconds(-5);
+        assert_eq!(
+            "5 seconds ago  ",
+            format!("{:15}", ht.with_options(options()))
+        );
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod next_change {
+    use std::time::Duration;
+    use time_humanize::{Accuracy, HumanTime, Tense};
+
+    /// Rough text of a duration of `nanos`, without tense
+    fn rough(nanos: u128) -> String {
+        let ht = HumanTime::from(Duration::from_nanos(nanos as u64));
+        ht.to_text_en(Accuracy::Rough, Tense::Present)
+    }
+
+    /// Precise text of `nanos` from now, negative for the past, cut to whole seconds if `whole`
+    fn precise_text(nanos: i128, whole: bool) -> String {
+        let magnitude = Duration::from_nanos(nanos.unsigned_abs() as u64);
+        let magnitude = match whole {
+            true => Duration::from_secs(magnitude.as_secs()),
+            false => magnitude,
+        };
+        let ht = match nanos < 0 {
+            true => -HumanTime::from(magnitude),
+            false => HumanTime::from(magnitude),
+        };
+        let tense = match ht.is_negative() {
+            true => Tense::Past,
+            false => Tense::Future,
+        };
+        ht.to_text_en(Accuracy::Precise, tense)
+    }
+
+    #[test]
+    fn minus_15s() {
+        let ht = HumanTime::from_seconds(-15);
+        assert_eq!(Duration::from_secs(1), ht.next_change(Accuracy::Rough));
+    }
+
+    #[test]
+    fn minus_5s() {
+        let ht = HumanTime::from_seconds(-5);
+        assert_eq!(Duration::from_secs(6), ht.next_change(Accuracy::Rough));
+    }
+
+    #[test]
+    fn minus_50s() {
+        // "a minute ago" until 91 seconds, then "2 minutes ago"
+        let ht = HumanTime::from_seconds(-50);
+        assert_eq!(Duration::from_secs(41), ht.next_change(Accuracy::Rough));
+    }
+
+    #[test]
+    fn minus_5m() {
+        let ht = HumanTime::from_seconds(-5 * 60 - 20);
+        assert_eq!(Duration::from_secs(40), ht.next_change(Accuracy::Rough));
+    }
+
+    #[test]
+    fn minus_3d() {
+        let ht = HumanTime::from_days(-3);
+        assert_eq!(Duration::from_secs(86400), ht.next_change(Accuracy::Rough));
+    }
+
+    #[test]
+    fn plus_15s() {
+        let ht = HumanTime::from_seconds(15);
+        assert_eq!(Duration::from_nanos(1), ht.next_change(Accuracy::Rough));
+    }
+
+    #[test]
+    fn plus_5m_30s() {
+        // "in 5 minutes" until the duration drops below 5 minutes
+        let ht = HumanTime::from_seconds(5 * 60 + 30);
+        assert_eq!(
+            Duration::from_secs(30) + Duration::from_nanos(1),
+            ht.next_change(Accuracy::Rough)
+        );
+    }
+
+    #[test]
+    fn plus_5s() {
+        // "now" lasts until 11 seconds have passed since
+        let ht = HumanTime::from_seconds(5);
+        assert_eq!(Duration::from_secs(16), ht.next_change(Accuracy::Rough));
+    }
+
+    #[test]
+    fn precise() {
+        let ht = HumanTime::from_seconds(-95);
+        assert_eq!(Duration::from_nanos(1), ht.next_change(Accuracy::Precise));
+
+        let ht = HumanTime::from(Duration::from_millis(1500));
+        assert_eq!(Duration::from_nanos(1), ht.next_change(Accuracy::Precise));
+    }
+
+    #[test]
+    fn precise_in_seconds() {
+        let ht = -HumanTime::from(Duration::from_millis(95_250));
+        assert_eq!(
+            Duration::from_millis(750),
+            ht.next_change_in_seconds(Accuracy::Precise)
+        );
+
+        let ht = HumanTime::from(Duration::from_millis(95_250));
+        assert_eq!(
+            Duration::from_millis(250) + Duration::from_nanos(1),
+            ht.next_change_in_seconds(Accuracy::Precise)
+        );
+    }
+
+    #[test]
+    fn precise_text_changes_exactly_at_next_change() {
+        let near_zero = [-1_000_000_000, -500_000_000, 0, 500_000_000, 1_000_000_000];
+        let values = (-3_000_000_000_000i128..3_000_000_000_000).step_by(99_999_999_977);
+        for nanos in values.chain(near_zero.iter().copied()) {
+            for &whole in &[false, true] {
+                let ht = match nanos < 0 {
+                    true => -HumanTime::from(Duration::from_nanos(nanos.unsigned_abs() as u64)),
+                    false => HumanTime::from(Duration::from_nanos(nanos as u64)),
+                };
+                let change = match whole {
+                    true => ht.next_change_in_seconds(Accuracy::Precise),
+                    false => ht.next_change(Accuracy::Precise),
+                };
+                // Time passing moves the point in time towards the past
+                let change = change.as_nanos() as i128;
+
+                let text = precise_text(nanos, whole);
+                assert_eq!(
+                    text,
+                    precise_text(nanos - change + 1, whole),
+                    "at {}ns",
+                    nanos
+                );
+                assert_ne!(text, precise_text(nanos - change, whole), "at {}ns", nanos);
+            }
+        }
+    }
+
+    #[test]
+    fn past_text_changes_exactly_at_next_change() {
+        for secs in (0..2_000_000u128).step_by(997) {
+            let nanos = secs * 1_000_000_000;
+            let ht = HumanTime::from(-(secs as i64));
+            let change = ht.next_change(Accuracy::Rough).as_nanos();
+
+            assert_eq!(rough(nanos), rough(nanos + change - 1), "at -{}s", secs);
+            assert_ne!(rough(nanos), rough(nanos + change), "at -{}s", secs);
+        }
+    }
+
+    #[test]
+    fn future_text_changes_exactly_at_next_change() {
+        for secs in (11..2_000_000u128).step_by(997) {
+            let nanos = secs * 1_000_000_000;
+            let ht = HumanTime::from(Duration::from_nanos(nanos as u64));
+            let change = ht.next_change(Accuracy::Rough).as_nanos();
+
+            assert_eq!(rough(nanos), rough(nanos - change + 1), "at {}s", secs);
+            assert_ne!(rough(nanos), rough(nanos - change), "at {}s", secs);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+macro_rules! parse_test {
+    ($($name:ident: $text:expr, $seconds:expr, $nanos:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let ht: HumanTime = $text.parse().unwrap();
+            assert_eq!($seconds, ht.as_secs());
+            assert_eq!($nanos, ht.duration().subsec_nanos());
+        })+
+    }
+}
+
+#[cfg(test)]
+mod parse {
+    use time_humanize::{HumanTime, ParseError, Unit};
+
+    // test_name: "Text", seconds, subsecond nanoseconds
+    parse_test! {
+        now: "now", 0, 0,
+        in_2_days: "in 2 days", 2 * 86400, 0,
+        days_ago: "2 days ago", -2 * 86400, 0,
+        an_hour_ago: "an hour ago", -3600, 0,
+        list: "1 hour, 2 minutes and 3 seconds", 3723, 0,
+        compact: "1h30m", 5400, 0,
+        compact_spaced: "1h 30m 15s", 5415, 0,
+        millis: "12345ms", 12, 345_000_000,
+        micros: "5 µs", 0, 5_000,
+        fraction: "1.5 hours", 5400, 0,
+        leading_dot: ".5s", 0, 500_000_000,
+        years: "5 years", 5 * 365 * 86400, 0,
+        upper_case: "In 3 Weeks", 3 * 7 * 86400, 0,
+        clock: "01:30:00", 5400, 0,
+        clock_minutes: "05:30", 330, 0,
+        clock_past: "-1:02:03.5", -3723, 500_000_000,
+        clock_plus: "+05:30", 330, 0,
+        minus: "-5m", -300, 0,
+        plus: "+1h30m", 5400, 0,
+        minus_list: "-1 hour and 30 minutes", -5400, 0,
+        round_trip: &format!("{:#}", HumanTime::from_seconds(-95)), -95, 0,
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Err(ParseError::Empty), "".parse::<HumanTime>());
+        assert_eq!(Err(ParseError::Empty), "in ".parse::<HumanTime>());
+        assert_eq!(
+            Err(ParseError::MissingUnit("5".into())),
+            "5".parse::<HumanTime>()
+        );
+        assert_eq!(
+            Err(ParseError::UnknownUnit("fortnights".into())),
+            "5 fortnights".parse::<HumanTime>()
+        );
+        assert_eq!(
+            Err(ParseError::InvalidNumber("1.2.3".into())),
+            "1.2.3 hours".parse::<HumanTime>()
+        );
+        assert_eq!(
+            Err(ParseError::Overflow),
+            "99999999999999 years".parse::<HumanTime>()
+        );
+    }
+
+    #[test]
+    fn misplaced_signs() {
+        for text in [
+            "in -3h",
+            "--3m",
+            "-5m ago",
+            "+2 days ago",
+            "1h-30m",
+            "-+5s",
+            "--01:30",
+        ] {
+            assert_eq!(
+                Err(ParseError::MisplacedSign(text.into())),
+                text.parse::<HumanTime>(),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(
+            "misplaced sign in \"in -3h\", only a leading \"-\" or \"+\" without \"in\" or \"ago\" is allowed",
+            "in -3h".parse::<HumanTime>().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn error_messages() {
+        let message = |text: &str| text.parse::<HumanTime>().unwrap_err().to_string();
+
+        assert_eq!(
+            "unknown unit \"hoursss\", did you mean \"hours\"?",
+            message("5 hoursss")
+        );
+        assert_eq!("unknown unit \"fortnights\"", message("5 fortnights"));
+        assert_eq!(
+            "missing unit after \"5\", e.g. \"5s\" or \"5 minutes\"",
+            message("5")
+        );
+        assert_eq!(
+            Err(ParseError::InvalidNumber("1.5".into())),
+            "1.5:00:00".parse::<HumanTime>()
+        );
+    }
+
+    #[test]
+    fn unit() {
+        assert_eq!(Ok(Unit::Hour), "hours".parse());
+        assert_eq!(Ok(Unit::Month), "mo".parse());
+        assert_eq!(Ok(Unit::Microsecond), "µs".parse());
+    }
+}
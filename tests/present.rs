@@ -57,25 +57,25 @@ mod duration {
         // minus_11d: Duration::days(-11), "2 weeks", "1 week and 4 days",
         // plus_4w: Duration::weeks(4), "4 weeks", "4 weeks",
         // minus_4w: Duration::weeks(-4), "4 weeks", "4 weeks",
-        // plus_30d: Duration::days(30), "a month", "1 month",
+        plus_30d: Duration::from_secs(86400 * 30), "a month", "1 month",
         // minus_30d: Duration::days(-30), "a month", "1 month",
-        // plus_45d: Duration::days(45), "a month", "1 month, 2 weeks and 1 day",
+        plus_45d: Duration::from_secs(86400 * 45), "a month", "1 month, 2 weeks and 1 day",
         // minus_45d: Duration::days(-45), "a month", "1 month, 2 weeks and 1 day",
-        // plus_46d: Duration::days(46), "2 months", "1 month, 2 weeks and 2 days",
+        plus_46d: Duration::from_secs(86400 * 46), "2 months", "1 month, 2 weeks and 2 days",
         // minus_46d: Duration::days(-46), "2 months", "1 month, 2 weeks and 2 days",
-        // plus_24w: Duration::weeks(24), "5 months", "5 months, 2 weeks and 4 days",
+        plus_24w: Duration::from_secs(86400 * 7 * 24), "5 months", "5 months, 2 weeks and 4 days",
         // minus_24w: Duration::weeks(-24), "5 months", "5 months, 2 weeks and 4 days",
-        // plus_26w: Duration::weeks(26), "6 months", "6 months and 2 days",
+        plus_26w: Duration::from_secs(86400 * 7 * 26), "6 months", "6 months and 2 days",
         // minus_26w: Duration::weeks(-26), "6 months", "6 months and 2 days",
-        // plus_50w: Duration::weeks(50), "a year", "11 months, 2 weeks and 6 days",
+        plus_50w: Duration::from_secs(86400 * 7 * 50), "a year", "11 months, 2 weeks and 6 days",
         // minus_50w: Duration::weeks(-50), "a year", "11 months, 2 weeks and 6 days",
-        // plus_100w: Duration::weeks(100), "2 years", "1 year, 11 months and 5 days",
+        plus_100w: Duration::from_secs(86400 * 7 * 100), "2 years", "1 year, 11 months and 5 days",
         // minus_100w: Duration::weeks(-100), "2 years", "1 year, 11 months and 5 days",
-        // plus_101w: Duration::weeks(101), "2 years", "1 year, 11 months, 1 week and 5 days",
+        plus_101w: Duration::from_secs(86400 * 7 * 101), "2 years", "1 year, 11 months, 1 week and 5 days",
         // minus_101w: Duration::weeks(-101), "2 years", "1 year, 11 months, 1 week and 5 days",
-        // plus_120w: Duration::weeks(120), "2 years", "2 years, 3 months, 2 weeks and 6 days",
+        plus_120w: Duration::from_secs(86400 * 7 * 120), "2 years", "2 years, 3 months, 2 weeks and 6 days",
         // minus_120w: Duration::weeks(-120), "2 years", "2 years, 3 months, 2 weeks and 6 days",
-        // plus_200w: Duration::weeks(200), "3 years", "3 years, 10 months and 5 days",
+        plus_200w: Duration::from_secs(86400 * 7 * 200), "3 years", "3 years, 10 months and 5 days",
         // minus_200w: Duration::weeks(-200), "3 years", "3 years, 10 months and 5 days",
     }
 }
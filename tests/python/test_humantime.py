@@ -0,0 +1,78 @@
+from datetime import timedelta
+
+import pytest
+
+from time_humanize import HumanTime
+
+
+@pytest.mark.parametrize(
+    "seconds, expected",
+    [
+        (0, "now"),
+        (5400, "in an hour"),
+        (-5400, "an hour ago"),
+        (-90.5, "a minute ago"),
+    ],
+)
+def test_str(seconds, expected):
+    assert str(HumanTime.from_seconds(seconds)) == expected
+
+
+@pytest.mark.parametrize(
+    "seconds, precise, tense, expected",
+    [
+        (5400, True, None, "in 1 hour and 30 minutes"),
+        (-5400, True, None, "1 hour and 30 minutes ago"),
+        (5400, False, None, "in an hour"),
+        (5400, True, "present", "1 hour and 30 minutes"),
+        (5400, False, "past", "an hour ago"),
+    ],
+)
+def test_format(seconds, precise, tense, expected):
+    assert HumanTime.from_seconds(seconds).format(precise=precise, tense=tense) == expected
+
+
+def test_format_invalid_tense():
+    with pytest.raises(ValueError):
+        HumanTime.from_seconds(60).format(tense="soon")
+
+
+def test_from_seconds_invalid():
+    with pytest.raises(ValueError, match="finite"):
+        HumanTime.from_seconds(float("nan"))
+    with pytest.raises(ValueError, match="out of range"):
+        HumanTime.from_seconds(1e30)
+
+
+def test_from_timedelta():
+    assert HumanTime.from_timedelta(timedelta(hours=1, minutes=30)) == HumanTime.from_seconds(5400)
+    # -1.5 seconds is stored as -1 day, 86398 seconds and 500000 microseconds
+    ht = HumanTime.from_timedelta(timedelta(seconds=-1.5))
+    assert ht.total_seconds() == -1.5
+    assert ht.format(precise=True) == "1 second and 500 ms ago"
+
+
+@pytest.mark.parametrize("delta", [timedelta.max, timedelta.min, timedelta(days=300_000)])
+def test_from_timedelta_long(delta):
+    assert HumanTime.from_timedelta(delta).total_seconds() == delta.total_seconds()
+
+
+@pytest.mark.parametrize(
+    "text, seconds",
+    [
+        ("in 2 days", 172800),
+        ("5 minutes ago", -300),
+        ("1h30m", 5400),
+    ],
+)
+def test_parse(text, seconds):
+    assert HumanTime.parse(text).total_seconds() == seconds
+
+
+def test_parse_invalid():
+    with pytest.raises(ValueError):
+        HumanTime.parse("whenever")
+
+
+def test_repr():
+    assert repr(HumanTime.from_seconds(-90)) == "HumanTime.from_seconds(-90.0)"
@@ -0,0 +1,96 @@
+macro_rules! range_test {
+    ($($name:ident: $range:expr, $rough:expr, $precise:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let range: HumanRange = $range;
+            assert_eq!($rough, format!("{}", range));
+            assert_eq!($precise, format!("{:#}", range));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod range {
+    use time_humanize::{
+        Accuracy, FormatOptions, HumanRange, HumanTime, NumberStyle, RangeStyle, Style, Tense,
+    };
+
+    fn between(a: i64, b: i64) -> HumanRange {
+        HumanRange::new(HumanTime::from_seconds(a), HumanTime::from_seconds(b))
+    }
+
+    const M: i64 = 60;
+    const H: i64 = 60 * M;
+    const D: i64 = 24 * H;
+
+    // test_name: HumanRange, "Rough text", "Precise text"
+    range_test! {
+        hours: between(2 * H, 3 * H), "in 2–3 hours", "in 2–3 hours",
+        swapped: between(3 * H, 2 * H), "in 2–3 hours", "in 2–3 hours",
+        days_ago: between(-2 * D, -3 * D), "2–3 days ago", "2–3 days ago",
+        minute_to_minutes: between(60, 150), "in 1–2 minutes", "in 1 minute to 2 minutes and 30 seconds",
+        different_units: between(50 * M, 3 * H), "in 1–3 hours", "in 50 minutes to 3 hours",
+        same_rough_text: between(H, 80 * M), "in an hour", "in 1 hour to 1 hour and 20 minutes",
+        from_zero: between(0, 5 * M), "within 5 minutes", "within 5 minutes",
+        from_near_zero: between(5, -5 * M), "up to 5 minutes ago", "5 minutes ago to in 5 seconds",
+        past_to_future: between(-5 * M, 10 * M), "5 minutes ago to in 10 minutes", "5 minutes ago to in 10 minutes",
+        future_to_past: between(2 * H, -3 * H), "3 hours ago to in 2 hours", "3 hours ago to in 2 hours",
+        near_zero: between(2, 5), "now", "in 2–5 seconds",
+        at_most: HumanRange::at_most(HumanTime::from_minutes(-5)), "up to 5 minutes ago", "up to 5 minutes ago",
+        at_least: HumanRange::at_least(HumanTime::from_days(2)), "in at least 2 days", "in at least 2 days",
+        at_least_ago: HumanRange::at_least(HumanTime::from_days(-2)), "at least 2 days ago", "at least 2 days ago",
+    }
+
+    #[test]
+    fn present() {
+        let range = between(2 * H, 3 * H);
+        assert_eq!(
+            "2–3 hours",
+            range.to_text_en(Accuracy::Rough, Tense::Present)
+        );
+        assert_eq!(
+            "up to 3 hours",
+            HumanRange::at_most(HumanTime::from_hours(3))
+                .to_text_en(Accuracy::Rough, Tense::Present)
+        );
+    }
+
+    #[test]
+    fn words() {
+        let range = between(-5 * M, -10 * M).style(RangeStyle::Words);
+        assert_eq!("between 5 and 10 minutes ago", range.to_string());
+
+        let range = between(50 * M, 3 * H).style(RangeStyle::Words);
+        assert_eq!("in between 50 minutes and 3 hours", format!("{:#}", range));
+
+        let range = between(-5 * M, 10 * M).style(RangeStyle::Words);
+        assert_eq!("between 5 minutes ago and in 10 minutes", range.to_string());
+    }
+
+    #[test]
+    fn options() {
+        let range = between(2 * H, 3 * H);
+        let words = FormatOptions::new().numbers(NumberStyle::Words);
+        assert_eq!(
+            "two–three hours",
+            range.to_text(Accuracy::Rough, Tense::Present, &words)
+        );
+
+        let fractional = FormatOptions::new().style(Style::Fractional {
+            unit: None,
+            decimals: 1,
+        });
+        assert_eq!(
+            "2.0 hours to 3.0 hours",
+            range.to_text(Accuracy::Rough, Tense::Present, &fractional)
+        );
+    }
+
+    #[test]
+    fn ends() {
+        let range = between(3 * H, 2 * H);
+        assert_eq!(Some(HumanTime::from_hours(2)), range.min());
+        assert_eq!(Some(HumanTime::from_hours(3)), range.max());
+        assert_eq!(None, HumanRange::at_least(HumanTime::from_hours(2)).max());
+    }
+}
@@ -0,0 +1,138 @@
+macro_rules! interval_test {
+    ($($name:ident: $secs:expr, $rough:expr, $precise:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let interval = HumanInterval::from(Duration::from_secs_f64($secs));
+            assert_eq!($rough, interval.to_text_en(Accuracy::Rough));
+            assert_eq!($precise, interval.to_text_en(Accuracy::Precise));
+        })+
+    }
+}
+
+macro_rules! rate_test {
+    ($($name:ident: $per_second:expr, $rough:expr, $precise:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let rate = HumanRate::per_second($per_second);
+            assert_eq!($rough, rate.to_text_en(Accuracy::Rough));
+            assert_eq!($precise, rate.to_text_en(Accuracy::Precise));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod interval {
+    use std::time::Duration;
+    use time_humanize::{Accuracy, HumanInterval};
+
+    const H: f64 = 3600.0;
+    const D: f64 = 24.0 * H;
+
+    // test_name: interval in seconds, "Rough text", "Precise text"
+    interval_test! {
+        zero: 0.0, "continuously", "continuously",
+        second: 1.0, "every second", "every second",
+        seconds: 45.0, "every 45 seconds", "every 45 seconds",
+        minute: 60.0, "every minute", "every minute",
+        twenty_minutes: 20.0 * 60.0, "every 20 minutes", "every 20 minutes",
+        hour: H, "every hour", "every hour",
+        ninety_minutes: 1.5 * H, "every hour", "every 1 hour and 30 minutes",
+        twelve_hours: 12.0 * H, "every 12 hours", "every 12 hours",
+        day: D, "every day", "every day",
+        two_days: 2.0 * D, "every 2 days", "every 2 days",
+        half_week: 3.5 * D, "twice a week", "twice a week",
+        third_week: 56.0 * H, "3 times per week", "3 times per week",
+        almost_half_week: 3.4 * D, "about twice a week", "every 3 days, 9 hours and 36 minutes",
+        week: 7.0 * D, "every week", "every week",
+        year: 365.0 * D, "every year", "every year",
+        two_years: 730.0 * D, "every 2 years", "every 2 years",
+        half_second: 0.5, "twice a second", "twice a second",
+        tenth_second: 0.1, "10 times per second", "10 times per second",
+    }
+}
+
+#[cfg(test)]
+mod rate {
+    use std::time::Duration;
+    use time_humanize::{Accuracy, FormatOptions, Frequency, HumanRate, Locale, NumberStyle, Unit};
+
+    // test_name: occurrences per second, "Rough text", "Precise text"
+    rate_test! {
+        never: 0.0, "never", "never",
+        negative: -1.0, "never", "never",
+        nan: f64::NAN, "never", "never",
+        infinite: f64::INFINITY, "continuously", "continuously",
+        once_a_second: 1.0, "once a second", "once a second",
+        per_second: 120.0, "120 times per second", "120 times per second",
+        fraction_per_second: 2.5, "about 3 per second", "2.5 per second",
+        per_minute: 0.048, "about 3 per minute", "2.88 per minute",
+        almost_once_a_minute: 0.95 / 60.0, "about once a minute", "57 times per hour",
+        once_an_hour: 1.0 / 3600.0, "once an hour", "once an hour",
+        three_per_hour: 3.0 / 3600.0, "3 times per hour", "3 times per hour",
+        twice_a_week: 2.0 / (7.0 * 86400.0), "twice a week", "twice a week",
+        every_two_years: 0.5 / (365.0 * 86400.0), "every 2 years", "every 2 years",
+    }
+
+    #[test]
+    fn new() {
+        let rate = HumanRate::new(6.0, Duration::from_secs(2 * 3600));
+        assert_eq!("3 times per hour", rate.to_string());
+        assert_eq!(
+            Some(Duration::from_secs(1200)),
+            rate.to_interval().map(|i| i.interval())
+        );
+        assert_eq!(None, HumanRate::per_second(0.0).to_interval());
+    }
+
+    #[test]
+    fn words() {
+        let options = FormatOptions::new().numbers(NumberStyle::Words);
+        let rate = HumanRate::per_second(3.0 / 60.0);
+        assert_eq!(
+            "three times per minute",
+            rate.to_text(Accuracy::Rough, &options)
+        );
+
+        let interval = rate.to_interval().unwrap();
+        assert_eq!(
+            "every twenty seconds",
+            interval.to_text(Accuracy::Rough, &options)
+        );
+    }
+
+    struct German;
+
+    impl Locale for German {
+        fn decimal_separator(&self) -> &str {
+            ","
+        }
+
+        fn frequency(&self, frequency: Frequency<'_>) -> String {
+            match frequency {
+                Frequency::Per {
+                    count,
+                    unit: Unit::Minute,
+                } => format!("{} pro Minute", count),
+                Frequency::Times {
+                    count,
+                    unit: Unit::Hour,
+                    ..
+                } => format!("{}-mal pro Stunde", count),
+                _ => "?".into(),
+            }
+        }
+    }
+
+    #[test]
+    fn locale() {
+        let options = FormatOptions::new().locale(&German);
+        assert_eq!(
+            "2,5 pro Minute",
+            HumanRate::per_second(2.5 / 60.0).to_text(Accuracy::Precise, &options)
+        );
+        assert_eq!(
+            "3-mal pro Stunde",
+            HumanRate::per_second(3.0 / 3600.0).to_text(Accuracy::Rough, &options)
+        );
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod stats {
+    use std::iter::FromIterator;
+    use std::time::Duration;
+    use time_humanize::{FormatOptions, HumanStats, HumanTime, Locale, Stat};
+
+    fn millis(values: &[u64]) -> HumanStats {
+        HumanStats::from_iter(values.iter().copied().map(Duration::from_millis))
+    }
+
+    fn ms(millis: u64) -> HumanTime {
+        HumanTime::from(Duration::from_millis(millis))
+    }
+
+    #[test]
+    fn statistics() {
+        let stats = millis(&[400, 100, 300, 200, 1000]);
+        assert_eq!(5, stats.count());
+        assert_eq!(ms(100), stats.min());
+        assert_eq!(ms(300), stats.median());
+        assert_eq!(ms(400), stats.mean());
+        assert_eq!(ms(1000), stats.max());
+        assert_eq!(ms(880), stats.p95());
+        assert_eq!(ms(976), stats.p99());
+        assert_eq!(ms(200), stats.percentile(25.0));
+        // Deviations of 300, 200, 100, 0 and 600 ms
+        assert_eq!(
+            HumanTime::from(Duration::from_nanos(316_227_766)),
+            stats.stddev()
+        );
+    }
+
+    #[test]
+    fn even_count() {
+        let stats = millis(&[1, 2, 3, 4]);
+        assert_eq!(HumanTime::from(Duration::from_micros(2500)), stats.median());
+    }
+
+    #[test]
+    fn single() {
+        let stats = millis(&[42]);
+        assert_eq!(ms(42), stats.min());
+        assert_eq!(ms(42), stats.p99());
+        assert_eq!(HumanTime::now(), stats.stddev());
+    }
+
+    #[test]
+    fn empty() {
+        let stats = millis(&[]);
+        assert_eq!(0, stats.count());
+        assert_eq!(HumanTime::now(), stats.median());
+        assert_eq!(HumanTime::now(), stats.mean());
+        assert_eq!(HumanTime::now(), stats.stddev());
+        assert_eq!("median 0.0s, p99 0.0s, max 0.0s", stats.to_string());
+    }
+
+    #[test]
+    fn summary() {
+        let stats = millis(&[1200, 2300, 2300, 3100, 14000, 62000]);
+        assert_eq!("median 2.7s, p99 59.6s, max 62.0s", stats.to_string());
+
+        let stats = millis(&[150_000, 200_000, 7_200_000]);
+        let all = [
+            Stat::Min,
+            Stat::Median,
+            Stat::Mean,
+            Stat::Percentile(95),
+            Stat::Max,
+        ];
+        assert_eq!(
+            "min 2.5m, median 3.3m, mean 41.9m, p95 108.3m, max 120.0m",
+            stats.summary(&all, &FormatOptions::default())
+        );
+
+        // No decimals from 100 units on
+        let stats = millis(&[100 * 86_400_000, 150 * 86_400_000]);
+        assert_eq!("median 125d, p99 150d, max 150d", stats.to_string());
+    }
+
+    struct German;
+
+    impl Locale for German {
+        fn decimal_separator(&self) -> &str {
+            ","
+        }
+
+        fn unit_symbol(&self, _: time_humanize::Unit) -> String {
+            " s".into()
+        }
+    }
+
+    #[test]
+    fn locale() {
+        let stats = millis(&[1500, 2500]);
+        let options = FormatOptions::new().locale(&German);
+        assert_eq!(
+            "median 2,0 s, max 2,5 s",
+            stats.summary(&[Stat::Median, Stat::Max], &options)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "percentile must be in [0, 100]")]
+    fn invalid_percentile() {
+        let _ = millis(&[1]).percentile(101.0);
+    }
+}
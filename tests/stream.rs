@@ -0,0 +1,87 @@
+macro_rules! stream_test {
+    ($($name:ident: $input:expr, $output:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            assert_eq!($output, humanize_text($input, &options()));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod stream {
+    use std::time::{Duration, SystemTime};
+    use time_humanize::{humanize_stream, humanize_text, Accuracy, StreamMode, StreamOptions};
+
+    // Wednesday, 2024-05-15 12:00:00 UTC
+    fn reference() -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_715_774_400)
+    }
+
+    fn options() -> StreamOptions {
+        StreamOptions::new(reference())
+    }
+
+    // test_name: "Input", "Output"
+    stream_test! {
+        rfc3339: "at 2024-05-12T12:00:00Z started", "at 3 days ago started",
+        rfc3339_space: "2024-05-15 11:00:00.5+00:00 x", "an hour ago x",
+        rfc3339_offset: "2024-05-15T14:00:00+02:00", "now",
+        rfc3339_future: "2024-05-15T14:00:00-02:00.", "in 4 hours.",
+        epoch_seconds: "[1715774100] ok", "[5 minutes ago] ok",
+        epoch_millis: "ts=1715774100000", "ts=5 minutes ago",
+        millis: "took 12345ms.", "took 12 seconds.",
+        short_seconds: "build took 5s", "build took 5 seconds",
+        short_millis: "request took 250ms", "request took 250 ms",
+        short_mixed: "took 10s500ms.", "took 10 seconds and 500 ms.",
+        compact: "retry in 1h30m, ok", "retry in an hour, ok",
+        unchanged: "pid 12345 uses 64bit v1.2.3 on 2nd try", "pid 12345 uses 64bit v1.2.3 on 2nd try",
+        inside_word: "abc1715774100 x95s", "abc1715774100 x95s",
+        unicode: "über 95s ünd", "über 2 minutes ünd",
+        leap_second: "2024-05-15T11:59:60Z", "now",
+        leap_day: "2024-02-29T12:00:00Z", "2 months ago",
+        invalid_second: "2024-05-15T11:00:61Z", "2024-05-15T11:00:61Z",
+        invalid_day: "2024-02-31T12:00:00Z", "2024-02-31T12:00:00Z",
+        invalid_leap_day: "2023-02-29T12:00:00Z", "2023-02-29T12:00:00Z",
+        invalid_april: "2024-04-31T12:00:00Z", "2024-04-31T12:00:00Z",
+    }
+
+    #[test]
+    fn annotate() {
+        let options = options().mode(StreamMode::Annotate);
+        assert_eq!(
+            "took 95s (2 minutes) at 1715774100 (5 minutes ago)",
+            humanize_text("took 95s at 1715774100", &options)
+        );
+    }
+
+    #[test]
+    fn precise() {
+        let options = options().accuracy(Accuracy::Precise);
+        assert_eq!(
+            "took 12 seconds and 345 ms",
+            humanize_text("took 12345ms", &options)
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let input = "a 95s\r\nb 2024-05-15T11:00:00Z\n\nlast 1715774100";
+        let mut output = vec![];
+        humanize_stream(input.as_bytes(), &mut output, &options()).unwrap();
+        assert_eq!(
+            "a 2 minutes\r\nb an hour ago\n\nlast 5 minutes ago",
+            String::from_utf8(output).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let input = b"a 95s\nb \xff 95s \xfe\nc 95s";
+        let mut output = vec![];
+        humanize_stream(&input[..], &mut output, &options()).unwrap();
+        assert_eq!(
+            &b"a 2 minutes\nb \xff 95s \xfe\nc 2 minutes"[..],
+            &output[..]
+        );
+    }
+}
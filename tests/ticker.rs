@@ -0,0 +1,152 @@
+#![cfg(feature = "tokio")]
+
+#[cfg(test)]
+mod ticker {
+    use std::time::{Duration, SystemTime};
+    use time_humanize::{Accuracy, Clock, FormatOptions, NearZero, RelativeTimeTicker, Style};
+    use tokio::time::{timeout, Instant};
+    use tokio_stream::StreamExt;
+
+    fn reference() -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_715_774_400)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn past() {
+        let target = reference() - Duration::from_secs(80);
+        let mut ticker = RelativeTimeTicker::with_reference(target, reference());
+        let start = Instant::now();
+
+        assert_eq!("a minute ago", ticker.tick().await);
+        assert_eq!("2 minutes ago", ticker.tick().await);
+        assert_eq!(Duration::from_secs(11), start.elapsed());
+        assert_eq!("3 minutes ago", ticker.tick().await);
+        assert_eq!(Duration::from_secs(100), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn future_through_now() {
+        let target = reference() + Duration::from_secs(13);
+        let mut ticker = RelativeTimeTicker::with_reference(target, reference());
+        let start = Instant::now();
+
+        assert_eq!("in 13 seconds", ticker.tick().await);
+        assert_eq!("in 12 seconds", ticker.tick().await);
+        assert_eq!("in 11 seconds", ticker.tick().await);
+        assert_eq!("now", ticker.tick().await);
+        assert_eq!("11 seconds ago", ticker.tick().await);
+        assert_eq!(Duration::from_secs(24), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn precise() {
+        let target = reference() - Duration::from_secs(59);
+        let mut ticker =
+            RelativeTimeTicker::with_reference(target, reference()).accuracy(Accuracy::Precise);
+
+        assert_eq!("59 seconds ago", ticker.tick().await);
+        assert_eq!("1 minute ago", ticker.tick().await);
+        assert_eq!("1 minute and 1 second ago", ticker.tick().await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn current_does_not_advance() {
+        let target = reference() - Duration::from_secs(80);
+        let mut ticker = RelativeTimeTicker::with_reference(target, reference());
+
+        assert_eq!("a minute ago", ticker.current());
+        assert_eq!("a minute ago", ticker.tick().await);
+        assert_eq!("a minute ago", ticker.current());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stream() {
+        let target = reference() - Duration::from_secs(80);
+        let ticker = RelativeTimeTicker::with_reference(target, reference());
+        let start = Instant::now();
+
+        let labels: Vec<String> = ticker.take(3).collect().await;
+        assert_eq!(
+            vec!["a minute ago", "2 minutes ago", "3 minutes ago"],
+            labels
+        );
+        assert_eq!(Duration::from_secs(100), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stream_after_cancelled_tick() {
+        let target = reference() - Duration::from_secs(80);
+        let mut ticker = RelativeTimeTicker::with_reference(target, reference());
+        let start = Instant::now();
+
+        assert_eq!(Some("a minute ago".to_string()), ticker.next().await);
+        assert!(timeout(Duration::from_secs(5), ticker.tick())
+            .await
+            .is_err());
+        assert_eq!(Some("2 minutes ago".to_string()), ticker.next().await);
+        assert_eq!(Duration::from_secs(11), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn near_zero() {
+        let target = reference() + Duration::from_secs(5);
+        let options = FormatOptions::new().near_zero(NearZero::default());
+        let mut ticker = RelativeTimeTicker::with_reference(target, reference()).options(options);
+        let start = Instant::now();
+
+        assert_eq!("in a moment", ticker.tick().await);
+        assert_eq!("just now", ticker.tick().await);
+        // tokio's timer rounds the sleep up to the next millisecond
+        assert_eq!(Duration::from_millis(4001), start.elapsed());
+        assert_eq!("11 seconds ago", ticker.tick().await);
+        assert_eq!(Duration::from_secs(16), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fractional() {
+        let target = reference() - Duration::from_secs(3600);
+        let options = FormatOptions::new().style(Style::Fractional {
+            unit: None,
+            decimals: 2,
+        });
+        let mut ticker = RelativeTimeTicker::with_reference(target, reference()).options(options);
+        let start = Instant::now();
+
+        assert_eq!("1.00 hour ago", ticker.tick().await);
+        assert_eq!("1.01 hours ago", ticker.tick().await);
+        assert_eq!(Duration::from_secs(19), start.elapsed());
+        assert_eq!("1.02 hours ago", ticker.tick().await);
+        assert_eq!(Duration::from_secs(55), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn clock() {
+        let target = reference() + Duration::from_millis(1500);
+        let options = FormatOptions::new().style(Style::Clock(Clock::default()));
+        let mut ticker = RelativeTimeTicker::with_reference(target, reference()).options(options);
+        let start = Instant::now();
+
+        assert_eq!("00:00:01", ticker.tick().await);
+        assert_eq!("00:00:00", ticker.tick().await);
+        assert_eq!("-00:00:01", ticker.tick().await);
+        assert_eq!(Duration::from_millis(2500), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn clock_fraction() {
+        let target = reference() - Duration::from_millis(1250);
+        let clock = Clock {
+            fraction: 1,
+            ..Clock::default()
+        };
+        let options = FormatOptions::new().style(Style::Clock(clock));
+        let mut ticker = RelativeTimeTicker::with_reference(target, reference()).options(options);
+        let start = Instant::now();
+
+        assert_eq!("-00:00:01.2", ticker.tick().await);
+        assert_eq!("-00:00:01.3", ticker.tick().await);
+        assert_eq!(Duration::from_millis(50), start.elapsed());
+        assert_eq!("-00:00:01.4", ticker.tick().await);
+        assert_eq!(Duration::from_millis(150), start.elapsed());
+    }
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "tracing")]
+
+#[cfg(test)]
+mod trace {
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use time_humanize::{HumanDuration, HumanDurationFields, Unit};
+
+    /// Writer collecting the output of a subscriber
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn text(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    /// Console output of the events logged by `f`
+    fn console(fields: HumanDurationFields, f: impl FnOnce()) -> String {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .without_time()
+            .with_level(false)
+            .with_target(false)
+            .fmt_fields(fields)
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, f);
+        buffer.text()
+    }
+
+    #[test]
+    fn suffixes() {
+        let output = console(HumanDurationFields::new(), || {
+            tracing::info!(elapsed_ms = 1234u64, wait_s = 90, "done")
+        });
+        assert_eq!(
+            "done elapsed_ms=1 second and 234 ms wait_s=1 minute and 30 seconds\n",
+            output
+        );
+
+        let output = console(HumanDurationFields::new(), || {
+            tracing::info!(latency_us = 2.5, took_ns = 1_500_000_000u64)
+        });
+        assert_eq!(
+            "latency_us=2 µs and 500 ns took_ns=1 second and 500 ms\n",
+            output
+        );
+    }
+
+    #[test]
+    fn other_fields() {
+        let output = console(HumanDurationFields::new(), || {
+            tracing::info!(count = 5, delta_ms = -5, name_ms = "fast", ms = 3, "done")
+        });
+        assert_eq!("done count=5 delta_ms=-5 name_ms=\"fast\" ms=3\n", output);
+    }
+
+    #[test]
+    fn custom_fields() {
+        let fields = HumanDurationFields::new()
+            .field("elapsed", Unit::Millisecond)
+            .without_suffixes();
+        let output = console(fields, || tracing::info!(elapsed = 60_000, wait_s = 5));
+        assert_eq!("elapsed=1 minute wait_s=5\n", output);
+    }
+
+    #[test]
+    fn human_duration() {
+        let elapsed = HumanDuration(Duration::from_secs(95));
+        assert_eq!("1 minute and 35 seconds", elapsed.to_string());
+        assert_eq!("1 minute and 35 seconds", format!("{:?}", elapsed));
+
+        let output = console(
+            HumanDurationFields::new(),
+            || tracing::info!(elapsed = elapsed.as_value(), other = %elapsed),
+        );
+        assert_eq!(
+            "elapsed=1 minute and 35 seconds other=1 minute and 35 seconds\n",
+            output
+        );
+    }
+
+    #[test]
+    fn json_keeps_numbers() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let (console, json) = (Buffer::default(), Buffer::default());
+        let (console_writer, json_writer) = (console.clone(), json.clone());
+        let subscriber = tracing_subscriber::registry()
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .with_writer(move || console_writer.clone())
+                    .with_ansi(false)
+                    .without_time()
+                    .with_level(false)
+                    .with_target(false)
+                    .fmt_fields(HumanDurationFields::new()),
+            )
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .json()
+                    .with_writer(move || json_writer.clone())
+                    .without_time(),
+            );
+
+        tracing::subscriber::with_default(subscriber, || tracing::info!(elapsed_ms = 1234u64));
+        assert_eq!("elapsed_ms=1 second and 234 ms\n", console.text());
+        assert!(
+            json.text().contains("\"elapsed_ms\":1234"),
+            "{}",
+            json.text()
+        );
+    }
+}
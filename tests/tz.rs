@@ -0,0 +1,200 @@
+#![cfg(feature = "tz")]
+
+macro_rules! offset_test {
+    ($($name:ident: $zone:expr, $unix:expr, $offset:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            assert_eq!($offset, $zone.utc_offset(at($unix)));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod tz {
+    use std::env;
+    use std::time::{Duration, SystemTime};
+    use time_humanize::{English, HumanTime, TimeZone, TzError};
+
+    const H: i32 = 60 * 60;
+
+    fn at(unix: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(unix)
+    }
+
+    fn new_york() -> TimeZone {
+        TimeZone::from_tzif(include_bytes!("data/zoneinfo/America/New_York")).unwrap()
+    }
+
+    fn berlin() -> TimeZone {
+        TimeZone::from_tzif(include_bytes!("data/zoneinfo/Europe/Berlin")).unwrap()
+    }
+
+    fn sydney() -> TimeZone {
+        TimeZone::from_tzif(include_bytes!("data/zoneinfo/Australia/Sydney")).unwrap()
+    }
+
+    // test_name: zone, unix seconds, UTC offset in seconds
+    offset_test! {
+        // 2024-03-10 02:00 EST springs forward to 03:00 EDT
+        new_york_before_spring_forward: new_york(), 1_710_053_999, -5 * H,
+        new_york_after_spring_forward: new_york(), 1_710_054_000, -4 * H,
+        // 2024-11-03 02:00 EDT falls back to 01:00 EST
+        new_york_before_fall_back: new_york(), 1_730_613_599, -4 * H,
+        new_york_after_fall_back: new_york(), 1_730_613_600, -5 * H,
+        // Beyond the transitions of the file the rule of its footer applies
+        berlin_2040_before_spring_forward: berlin(), 2_216_249_999, H,
+        berlin_2040_after_spring_forward: berlin(), 2_216_250_000, 2 * H,
+        berlin_2040_before_fall_back: berlin(), 2_234_998_799, 2 * H,
+        berlin_2040_after_fall_back: berlin(), 2_234_998_800, H,
+        // Daylight saving time spans the turn of the year
+        sydney_2040_january: sydney(), 2_210_198_400, 11 * H,
+        sydney_2040_july: sydney(), 2_225_923_200, 10 * H,
+        berlin_1970: berlin(), 0, H,
+        utc: TimeZone::utc(), 1_710_054_000, 0,
+    }
+
+    #[test]
+    fn between_across_spring_forward() {
+        // Saturday, 2024-03-09 12:00 EST to Sunday 12:00 EDT
+        let (saturday, sunday) = (at(1_710_003_600), at(1_710_086_400));
+        assert_eq!(
+            HumanTime::from_hours(23),
+            HumanTime::between(sunday, saturday)
+        );
+
+        let ht = HumanTime::between_in(saturday, sunday, &new_york());
+        assert_eq!(HumanTime::from_days(-1), ht);
+        assert_eq!("1 day ago", format!("{:#}", ht));
+    }
+
+    #[test]
+    fn between_across_fall_back() {
+        // Saturday, 2024-11-02 12:00 EDT to Sunday 12:00 EST
+        let (saturday, sunday) = (at(1_730_563_200), at(1_730_653_200));
+        assert_eq!(
+            HumanTime::from_hours(25),
+            HumanTime::between(sunday, saturday)
+        );
+
+        let ht = HumanTime::between_in(sunday, saturday, &new_york());
+        assert_eq!(HumanTime::from_days(1), ht);
+        assert_eq!("in 1 day", format!("{:#}", ht));
+    }
+
+    #[test]
+    fn between_keeps_fractions() {
+        let reference = at(1_710_003_600);
+        let target = reference + Duration::from_millis(1500);
+        let ht = HumanTime::between_in(target, reference, &new_york());
+        assert_eq!(Duration::from_millis(1500), ht.duration());
+    }
+
+    #[test]
+    fn calendar_across_spring_forward() {
+        // Monday, 2024-03-11 00:30 EDT
+        let reference = at(1_710_131_400);
+        // Saturday, 2024-03-09 23:59 EST, which a fixed offset of EDT puts on Sunday
+        let ht = HumanTime::from_seconds(1_710_046_740 - 1_710_131_400);
+
+        assert_eq!(
+            "yesterday",
+            ht.to_calendar_text(reference, -4 * H, &English)
+        );
+        assert_eq!(
+            "last Saturday",
+            ht.to_calendar_text_in(reference, &new_york(), &English)
+        );
+    }
+
+    #[test]
+    fn calendar_wall_clock() {
+        // Sunday, 2024-03-31 14:00 CEST, the clocks sprang forward at 02:00 CET
+        let reference = at(1_711_886_400);
+        let text = |seconds| {
+            HumanTime::from_seconds(seconds).to_calendar_text_in(reference, &berlin(), &English)
+        };
+
+        assert_eq!("today at 03:00", text(-11 * 3600));
+        assert_eq!("today at 01:59", text(-11 * 3600 - 1));
+    }
+
+    #[test]
+    fn invalid_data() {
+        assert!(matches!(
+            TimeZone::from_tzif(b"not a zone"),
+            Err(TzError::InvalidData(_))
+        ));
+
+        let data = include_bytes!("data/zoneinfo/Europe/Berlin");
+        assert!(matches!(
+            TimeZone::from_tzif(&data[..data.len() / 2]),
+            Err(TzError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    fn malformed_footer() {
+        let data = include_bytes!("data/zoneinfo/Europe/Berlin");
+        // The footer is the POSIX TZ rule between the last two newlines
+        let start = data[..data.len() - 1]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .unwrap();
+        let with_footer = |footer: &str| {
+            let mut zone = data[..=start].to_vec();
+            zone.extend_from_slice(footer.as_bytes());
+            zone.push(b'\n');
+            TimeZone::from_tzif(&zone)
+        };
+
+        assert_eq!(berlin(), with_footer("CET-1CEST,M3.5.0,M10.5.0/3").unwrap());
+        for footer in [
+            "CET-99999999999999999999",
+            "CET-2562047788015216",
+            "CET-1:9223372036854775807",
+            "CET-596524",
+            "CET-2147483647:59:59CEST",
+            "CET-1CEST,M3.5.0/99999999999,M10.5.0/3",
+        ] {
+            assert!(
+                matches!(with_footer(footer), Err(TzError::InvalidData(_))),
+                "{}",
+                footer
+            );
+        }
+    }
+
+    #[test]
+    fn environment() {
+        env::set_var(
+            "TZDIR",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/zoneinfo"),
+        );
+        assert_eq!(berlin(), TimeZone::from_system("Europe/Berlin").unwrap());
+        assert!(matches!(
+            TimeZone::from_system("Europe/Atlantis"),
+            Err(TzError::Io(_))
+        ));
+        assert!(matches!(
+            TimeZone::from_system("../Europe/Berlin"),
+            Err(TzError::InvalidName(_))
+        ));
+
+        env::set_var("TZ", "America/New_York");
+        assert_eq!(new_york(), TimeZone::local().unwrap());
+
+        env::set_var("TZ", "CET-1CEST,M3.5.0,M10.5.0/3");
+        let zone = TimeZone::local().unwrap();
+        assert_eq!(
+            berlin().utc_offset(at(2_216_250_000)),
+            zone.utc_offset(at(2_216_250_000))
+        );
+        assert_eq!(H, zone.utc_offset(at(2_234_998_800)));
+
+        env::set_var("TZ", "<+0330>-3:30");
+        assert_eq!(3 * H + 1800, TimeZone::local().unwrap().utc_offset(at(0)));
+
+        env::set_var("TZ", "Not/A/Zone");
+        assert!(TimeZone::local().is_err());
+    }
+}
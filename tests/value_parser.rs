@@ -0,0 +1,92 @@
+#![cfg(feature = "clap")]
+
+#[cfg(test)]
+mod value_parser {
+    use std::time::Duration;
+
+    use clap::error::ErrorKind;
+    use clap::{Arg, Command};
+    use time_humanize::{DurationValueParser, HumanTime, HumanTimeValueParser};
+
+    fn command() -> Command {
+        Command::new("app")
+            .arg(
+                Arg::new("timeout")
+                    .long("timeout")
+                    .value_parser(DurationValueParser)
+                    .default_value(HumanTime::from_minutes(90)),
+            )
+            .arg(Arg::new("at").long("at").value_parser(HumanTimeValueParser))
+    }
+
+    fn timeout(args: &[&str]) -> Duration {
+        let matches =
+            command().get_matches_from(std::iter::once("app").chain(args.iter().copied()));
+        *matches.get_one::<Duration>("timeout").unwrap()
+    }
+
+    #[test]
+    fn compact_and_phrases() {
+        assert_eq!(Duration::from_secs(5400), timeout(&["--timeout", "1h30m"]));
+        assert_eq!(
+            Duration::from_secs(150),
+            timeout(&["--timeout", "2 minutes and 30 seconds"])
+        );
+        assert_eq!(Duration::from_secs(90), timeout(&["--timeout", "00:01:30"]));
+        assert_eq!(Duration::from_millis(250), timeout(&["--timeout", "250ms"]));
+    }
+
+    #[test]
+    fn default_value() {
+        assert_eq!(Duration::from_secs(5400), timeout(&[]));
+
+        let help = command().render_help().to_string();
+        assert!(
+            help.contains("[default: \"1 hour and 30 minutes\"]"),
+            "{}",
+            help
+        );
+    }
+
+    #[test]
+    fn human_time() {
+        let matches = command().get_matches_from(["app", "--at", "3 days ago"]);
+        assert_eq!(
+            Some(&HumanTime::from_days(-3)),
+            matches.get_one::<HumanTime>("at")
+        );
+    }
+
+    #[test]
+    fn suggestion() {
+        let err = command()
+            .try_get_matches_from(["app", "--timeout", "5 minuts"])
+            .unwrap_err();
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+
+        let message = err.to_string();
+        assert!(
+            message.contains("invalid value '5 minuts' for '--timeout"),
+            "{}",
+            message
+        );
+        assert!(message.contains("did you mean \"minutes\"?"), "{}", message);
+        assert!(message.contains("tip:"), "{}", message);
+    }
+
+    #[test]
+    fn past_duration() {
+        let err = command()
+            .try_get_matches_from(["app", "--timeout", "5 minutes ago"])
+            .unwrap_err();
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+        assert!(err
+            .to_string()
+            .contains("expected a duration like \"5 minutes\""));
+
+        let err = command()
+            .try_get_matches_from(["app", "--timeout=-5m"])
+            .unwrap_err();
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+    }
+}
@@ -0,0 +1,68 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+macro_rules! humanize_test {
+    ($($name:ident: $seconds:expr, [$($key:expr => $value:expr),*], $expected:expr,)+) => {
+        $(#[wasm_bindgen_test]
+        fn $name() {
+            let options = Object::new();
+            $(Reflect::set(&options, &$key.into(), &$value.into()).unwrap();)*
+            assert_eq!($expected, humanize($seconds, Some(options)).unwrap());
+        })+
+    }
+}
+
+#[cfg(test)]
+mod wasm {
+    use js_sys::{Object, Reflect};
+    use time_humanize::{wasm_humanize as humanize, wasm_parse as parse};
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    // test_name: seconds, [option => value], expected text
+    humanize_test! {
+        rough: 5400.0, [], "in an hour",
+        precise: 5400.0, ["precise" => true], "in 1 hour and 30 minutes",
+        past: -90.0, ["precise" => true], "1 minute and 30 seconds ago",
+        present: -90.0, ["precise" => true, "tense" => "present"], "1 minute and 30 seconds",
+        words: 180.0, ["numbers" => "words"], "in three minutes",
+        words_up_to: 720.0, ["numbers" => 10.0], "in 12 minutes",
+        fractional: 5400.0, ["style" => "fractional", "unit" => "hours"], "in 1.5 hours",
+        clock: -90.0, ["style" => "clock"], "-00:01:30",
+        separator: 3723.0, ["precise" => true, "separator" => " "], "in 1 hour 2 minutes 3 seconds",
+        serial_comma: 3723.0, ["precise" => true, "serialComma" => true], "in 1 hour, 2 minutes, and 3 seconds",
+        near_zero: -3.0, ["nearZero" => true], "just now",
+        qualifier: 86400.0, ["qualifier" => "business"], "in 1 business day",
+    }
+
+    #[wasm_bindgen_test]
+    fn without_options() {
+        assert_eq!("a minute ago", humanize(-60.0, None).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn invalid_options() {
+        let options = Object::new();
+        Reflect::set(&options, &"tense".into(), &"soon".into()).unwrap();
+        assert!(humanize(60.0, Some(options)).is_err());
+        assert!(humanize(f64::NAN, None).is_err());
+        assert!(humanize(1e30, None).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn near_zero_phrases() {
+        let phrases = Object::new();
+        Reflect::set(&phrases, &"past".into(), &"a moment ago".into()).unwrap();
+        Reflect::set(&phrases, &"future".into(), &"shortly".into()).unwrap();
+        let options = Object::new();
+        Reflect::set(&options, &"nearZero".into(), &JsValue::from(phrases)).unwrap();
+        assert_eq!("shortly", humanize(2.0, Some(options)).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn parse_text() {
+        assert_eq!(172_800.0, parse("in 2 days").unwrap());
+        assert_eq!(-300.0, parse("5 minutes ago").unwrap());
+        assert_eq!(5400.0, parse("1h30m").unwrap());
+        assert!(parse("whenever").is_err());
+    }
+}
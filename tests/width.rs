@@ -0,0 +1,53 @@
+macro_rules! width_test {
+    ($($name:ident: $seconds:expr, $format:literal, $text:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let ht = HumanTime::from_seconds($seconds);
+            assert_eq!($text, format!($format, ht));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod width {
+    use time_humanize::{FormatOptions, HumanTime, NumberStyle, Tense};
+
+    // 93784 seconds are 1 day, 2 hours, 3 minutes and 4 seconds
+    // test_name: seconds, format string, "Text"
+    width_test! {
+        rough: 93784, "{}", "in a day",
+        precise: 93784, "{:#}", "in 1 day, 2 hours, 3 minutes and 4 seconds",
+        two_units: 93784, "{:.2}", "in 1 day and 2 hours",
+        one_unit: 93784, "{:.1}", "in 1 day",
+        zero_units: 93784, "{:.0}", "in 1 day",
+        more_units_than_present: 93784, "{:.9}", "in 1 day, 2 hours, 3 minutes and 4 seconds",
+        past: -5400, "{:.1}", "1 hour ago",
+        zero: 0, "{:.2}", "in 0 seconds",
+        fits_exactly: 93784, "{:20}", "in 1 day and 2 hours",
+        drops_units: 93784, "{:19}", "in 1 day           ",
+        fits_all: 93784, "{:50}", "in 1 day, 2 hours, 3 minutes and 4 seconds        ",
+        too_narrow: 93784, "{:3}", "in 1 day",
+        right: 93784, "{:>15}", "       in 1 day",
+        center: 93784, "{:*^12}", "**in 1 day**",
+        width_and_precision: 93784, "{:>25.1}", "                 in 1 day",
+        alternate_width: 95, "{:#12}", "in 1 minute ",
+    }
+
+    #[test]
+    fn with_options() {
+        let ht = HumanTime::from_seconds(93784);
+        let options = FormatOptions::new().numbers(NumberStyle::Words);
+        assert_eq!(
+            "in one day and two hours",
+            format!("{:.2}", ht.with_options(options.clone()))
+        );
+        assert_eq!(
+            "in one day     ",
+            format!("{:15}", ht.with_options(options.clone()))
+        );
+        assert_eq!(
+            "one day ago",
+            format!("{:.1}", ht.with_options(options).tense(Tense::Past))
+        );
+    }
+}
@@ -0,0 +1,69 @@
+macro_rules! words_test {
+    ($($name:ident: $duration:expr, $style:expr, $rough:expr, $precise:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let ht = HumanTime::from($duration);
+            let options = FormatOptions::new().numbers($style);
+            let rough = ht.to_text(Accuracy::Rough, Tense::Past, &options);
+            let precise = ht.to_text(Accuracy::Precise, Tense::Future, &options);
+            assert_eq!($rough, rough);
+            assert_eq!($precise, precise);
+        })+
+    }
+}
+
+#[cfg(test)]
+mod words {
+    use time_humanize::{Accuracy, FormatOptions, HumanTime, Locale, NumberStyle, Tense};
+
+    // test_name: seconds, NumberStyle, "Rough past text", "Precise future text"
+    words_test! {
+        plus_15s: 15, NumberStyle::Words, "fifteen seconds ago", "in fifteen seconds",
+        plus_1m: 60, NumberStyle::Words, "a minute ago", "in one minute",
+        plus_45m: 45 * 60, NumberStyle::Words, "forty-five minutes ago", "in forty-five minutes",
+        plus_2h: 2 * 60 * 60, NumberStyle::Words, "two hours ago", "in two hours",
+        plus_2h_1s: 2 * 60 * 60 + 1, NumberStyle::Words, "two hours ago", "in two hours and one second",
+        digits: 45 * 60, NumberStyle::Digits, "45 minutes ago", "in 45 minutes",
+        up_to_ten: 12 * 60 * 60 + 5, NumberStyle::WordsUpTo(10), "12 hours ago", "in 12 hours and five seconds",
+    }
+
+    #[test]
+    fn large_years() {
+        let ht = HumanTime::from_years(123_456);
+        let options = FormatOptions::new().numbers(NumberStyle::Words);
+        assert_eq!(
+            "one hundred twenty-three thousand four hundred fifty-six years",
+            ht.to_text(Accuracy::Rough, Tense::Present, &options)
+        );
+    }
+
+    #[test]
+    fn u64_max() {
+        assert_eq!(
+            "eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four \
+             trillion seventy-three billion seven hundred nine million five hundred fifty-one \
+             thousand six hundred fifteen",
+            time_humanize::English.spell_number(u64::MAX)
+        );
+    }
+
+    struct Shouting;
+
+    impl Locale for Shouting {
+        fn spell_number(&self, n: u64) -> String {
+            time_humanize::English.spell_number(n).to_uppercase()
+        }
+    }
+
+    #[test]
+    fn locale_hook() {
+        let ht = HumanTime::from_minutes(-3);
+        let options = FormatOptions::new()
+            .locale(&Shouting)
+            .numbers(NumberStyle::Words);
+        assert_eq!(
+            "THREE minutes ago",
+            ht.to_text(Accuracy::Rough, Tense::Past, &options)
+        );
+    }
+}